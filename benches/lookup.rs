@@ -0,0 +1,187 @@
+//! Compare the sorted table lookups with the equivalent `match`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use gluten_keyboard::Code;
+
+/// The HID keyboard page conversion written as a `match`.
+fn hid_match(usage: u16) -> Code {
+    match usage {
+        0x04 => Code::KeyA,
+        0x05 => Code::KeyB,
+        0x06 => Code::KeyC,
+        0x07 => Code::KeyD,
+        0x08 => Code::KeyE,
+        0x09 => Code::KeyF,
+        0x0A => Code::KeyG,
+        0x0B => Code::KeyH,
+        0x0C => Code::KeyI,
+        0x0D => Code::KeyJ,
+        0x0E => Code::KeyK,
+        0x0F => Code::KeyL,
+        0x10 => Code::KeyM,
+        0x11 => Code::KeyN,
+        0x12 => Code::KeyO,
+        0x13 => Code::KeyP,
+        0x14 => Code::KeyQ,
+        0x15 => Code::KeyR,
+        0x16 => Code::KeyS,
+        0x17 => Code::KeyT,
+        0x18 => Code::KeyU,
+        0x19 => Code::KeyV,
+        0x1A => Code::KeyW,
+        0x1B => Code::KeyX,
+        0x1C => Code::KeyY,
+        0x1D => Code::KeyZ,
+        0x1E => Code::Digit1,
+        0x1F => Code::Digit2,
+        0x20 => Code::Digit3,
+        0x21 => Code::Digit4,
+        0x22 => Code::Digit5,
+        0x23 => Code::Digit6,
+        0x24 => Code::Digit7,
+        0x25 => Code::Digit8,
+        0x26 => Code::Digit9,
+        0x27 => Code::Digit0,
+        0x28 => Code::Enter,
+        0x29 => Code::Escape,
+        0x2A => Code::Backspace,
+        0x2B => Code::Tab,
+        0x2C => Code::Space,
+        0x2D => Code::Minus,
+        0x2E => Code::Equal,
+        0x2F => Code::BracketLeft,
+        0x30 => Code::BracketRight,
+        0x31 => Code::Backslash,
+        0x33 => Code::Semicolon,
+        0x34 => Code::Quote,
+        0x35 => Code::Backquote,
+        0x36 => Code::Comma,
+        0x37 => Code::Period,
+        0x38 => Code::Slash,
+        0x39 => Code::CapsLock,
+        0x3A => Code::F1,
+        0x3B => Code::F2,
+        0x3C => Code::F3,
+        0x3D => Code::F4,
+        0x3E => Code::F5,
+        0x3F => Code::F6,
+        0x40 => Code::F7,
+        0x41 => Code::F8,
+        0x42 => Code::F9,
+        0x43 => Code::F10,
+        0x44 => Code::F11,
+        0x45 => Code::F12,
+        0x46 => Code::PrintScreen,
+        0x47 => Code::ScrollLock,
+        0x48 => Code::Pause,
+        0x49 => Code::Insert,
+        0x4A => Code::Home,
+        0x4B => Code::PageUp,
+        0x4C => Code::Delete,
+        0x4D => Code::End,
+        0x4E => Code::PageDown,
+        0x4F => Code::ArrowRight,
+        0x50 => Code::ArrowLeft,
+        0x51 => Code::ArrowDown,
+        0x52 => Code::ArrowUp,
+        0x53 => Code::NumLock,
+        0x54 => Code::NumpadDivide,
+        0x55 => Code::NumpadMultiply,
+        0x56 => Code::NumpadSubtract,
+        0x57 => Code::NumpadAdd,
+        0x58 => Code::NumpadEnter,
+        0x59 => Code::Numpad1,
+        0x5A => Code::Numpad2,
+        0x5B => Code::Numpad3,
+        0x5C => Code::Numpad4,
+        0x5D => Code::Numpad5,
+        0x5E => Code::Numpad6,
+        0x5F => Code::Numpad7,
+        0x60 => Code::Numpad8,
+        0x61 => Code::Numpad9,
+        0x62 => Code::Numpad0,
+        0x63 => Code::NumpadDecimal,
+        0x64 => Code::IntlBackslash,
+        0x65 => Code::ContextMenu,
+        0x66 => Code::Power,
+        0x67 => Code::NumpadEqual,
+        0x68 => Code::F13,
+        0x69 => Code::F14,
+        0x6A => Code::F15,
+        0x6B => Code::F16,
+        0x6C => Code::F17,
+        0x6D => Code::F18,
+        0x6E => Code::F19,
+        0x6F => Code::F20,
+        0x70 => Code::F21,
+        0x71 => Code::F22,
+        0x72 => Code::F23,
+        0x73 => Code::F24,
+        0x74 => Code::Open,
+        0x75 => Code::Help,
+        0x77 => Code::Select,
+        0x79 => Code::Again,
+        0x7A => Code::Undo,
+        0x7B => Code::Cut,
+        0x7C => Code::Copy,
+        0x7D => Code::Paste,
+        0x7E => Code::Find,
+        0x7F => Code::AudioVolumeMute,
+        0x80 => Code::AudioVolumeUp,
+        0x81 => Code::AudioVolumeDown,
+        0x85 => Code::NumpadComma,
+        0x87 => Code::IntlRo,
+        0x88 => Code::KanaMode,
+        0x89 => Code::IntlYen,
+        0x8A => Code::Convert,
+        0x8B => Code::NonConvert,
+        0x90 => Code::Lang1,
+        0x91 => Code::Lang2,
+        0x92 => Code::Lang3,
+        0x93 => Code::Lang4,
+        0x94 => Code::Lang5,
+        0x9B => Code::Abort,
+        0xA3 => Code::Props,
+        0xB6 => Code::NumpadParenLeft,
+        0xB7 => Code::NumpadParenRight,
+        0xBB => Code::NumpadBackspace,
+        0xD0 => Code::NumpadMemoryStore,
+        0xD1 => Code::NumpadMemoryRecall,
+        0xD2 => Code::NumpadMemoryClear,
+        0xD3 => Code::NumpadMemoryAdd,
+        0xD4 => Code::NumpadMemorySubtract,
+        0xD8 => Code::NumpadClear,
+        0xD9 => Code::NumpadClearEntry,
+        0xE0 => Code::ControlLeft,
+        0xE1 => Code::ShiftLeft,
+        0xE2 => Code::AltLeft,
+        0xE3 => Code::MetaLeft,
+        0xE4 => Code::ControlRight,
+        0xE5 => Code::ShiftRight,
+        0xE6 => Code::AltRight,
+        0xE7 => Code::MetaRight,
+        _ => Code::Unidentified,
+    }
+}
+
+fn bench_hid(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_hid_usage");
+    group.bench_function("table", |b| {
+        b.iter(|| {
+            for usage in 0..=0xFF {
+                black_box(Code::from_hid_usage(black_box(usage)));
+            }
+        })
+    });
+    group.bench_function("match", |b| {
+        b.iter(|| {
+            for usage in 0..=0xFF {
+                black_box(hid_match(black_box(usage)));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_hid);
+criterion_main!(benches);
@@ -0,0 +1,89 @@
+//! Compare matching shortcuts in a list with the compiled shortcuts.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use gluten_keyboard::{
+    CompiledShortcuts, Key, KeyMatch, KeyPattern, KeyboardEvent, ModifierMatch, Modifiers,
+};
+
+const CHARACTERS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// 62 characters with 8 modifier combinations and 4 named keys.
+fn bindings() -> Vec<(KeyPattern<'static>, usize)> {
+    let mut bindings = Vec::new();
+    for i in 0..CHARACTERS.len() {
+        for bits in 0..8 {
+            let mut modifiers = Modifiers::empty();
+            modifiers.set(Modifiers::CONTROL, bits & 1 != 0);
+            modifiers.set(Modifiers::ALT, bits & 2 != 0);
+            modifiers.set(Modifiers::SHIFT, bits & 4 != 0);
+            bindings.push(KeyPattern {
+                modifiers: ModifierMatch::Exact(modifiers),
+                key: KeyMatch::Is(Key::Character(&CHARACTERS[i..i + 1])),
+            });
+        }
+    }
+    for key in &[Key::Enter, Key::Escape, Key::F1, Key::Tab] {
+        bindings.push(KeyPattern {
+            modifiers: ModifierMatch::Any,
+            key: KeyMatch::Is(*key),
+        });
+    }
+    bindings.into_iter().zip(0..).collect()
+}
+
+fn events() -> Vec<KeyboardEvent<'static>> {
+    let keys = [
+        Key::Character("a"),
+        Key::Character("Z"),
+        Key::Character("9"),
+        Key::Character("é"),
+        Key::Enter,
+        Key::ArrowUp,
+    ];
+    let modifiers = [
+        Modifiers::empty(),
+        Modifiers::CONTROL,
+        Modifiers::CONTROL | Modifiers::SHIFT,
+        Modifiers::META,
+    ];
+    keys.iter()
+        .flat_map(|key| {
+            modifiers.iter().map(move |modifiers| KeyboardEvent {
+                key: *key,
+                modifiers: *modifiers,
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+fn bench_shortcuts(c: &mut Criterion) {
+    let bindings = bindings();
+    assert_eq!(bindings.len(), 500);
+    let compiled: CompiledShortcuts<usize> = bindings.iter().cloned().collect();
+    let events = events();
+    let mut group = c.benchmark_group("match_event");
+    group.bench_function("linear", |b| {
+        b.iter(|| {
+            for event in &events {
+                black_box(
+                    bindings
+                        .iter()
+                        .find(|(pattern, _)| pattern.matches(black_box(event)))
+                        .map(|(_, value)| value),
+                );
+            }
+        })
+    });
+    group.bench_function("compiled", |b| {
+        b.iter(|| {
+            for event in &events {
+                black_box(compiled.match_event(black_box(event)));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_shortcuts);
+criterion_main!(benches);
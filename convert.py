@@ -1,4 +1,6 @@
 import re
+import unicodedata
+
 import requests
 
 
@@ -63,9 +65,22 @@ pub enum Key<'a> {
     /// A key string that corresponds to the character typed by the user,
     /// taking into account the user’s current locale setting, modifier state,
     /// and any system-level keyboard mapping overrides that are in effect.
+    ///
+    /// The string should be a single non-empty grapheme cluster of
+    /// printable characters. Use [`Key::character`] to check this invariant.
+    ///
+    /// The string is borrowed so constructing a key never allocates.
+    /// [`Key::from_char`] stores a single `char` in a caller provided buffer.
     Character(&'a str),
-    """, file=file)
-    display = parse(text)
+    
+    /// This key value is used when an implementation is unable to
+    /// identify another key value, due to either hardware,
+    /// platform, or software constraints.
+    ///
+    /// The payload optionally holds the raw platform value
+    /// (like a scancode or virtual key code) that could not be mapped.
+    Unidentified(Option<u32>),""", file=file)
+    display = [entry for entry in parse(text) if entry[0] != 'Unidentified']
 
     for i in range(13, 25):
         display.append([
@@ -84,7 +99,8 @@ impl Display for Key<'_> {
         use self::Key::*;
         match *self {
             Character(s) => f.write_str(s),
-    """, file=file)
+    
+            Unidentified(_) => f.write_str("Unidentified"),""", file=file)
     print_display_entries(display, file)
     print("""
         }
@@ -96,7 +112,8 @@ impl<'a> Key<'a> {
     pub fn parse(s: &'a str) -> Result<Self, UnrecognizedKeyError> {
         use Key::*;
         match s {
-            s if is_key_string(s) => Ok(Character(s)),""", file=file)
+            s if is_key_string(s) => Ok(Character(s)),
+            "Unidentified" => Ok(Unidentified(None)),""", file=file)
     print_from_str_entries(display, file)
     print("""
             _ => Err(UnrecognizedKeyError),
@@ -223,6 +240,13 @@ impl Display for Code {
     }
 }
 
+/// All codes in declaration order, so the index of a code is its discriminant.
+#[cfg(feature = "alloc")]
+pub(crate) const CODES: &[Code] = &[""", file=file)
+    for [key, doc_comment, alternatives] in display:
+        print("    Code::{},".format(key), file=file)
+    print("""];
+
 impl FromStr for Code {
     type Err = UnrecognizedCodeError;
 
@@ -251,6 +275,61 @@ impl Error for UnrecognizedCodeError {}
     """, file=file)
 
 
+def convert_compositions(file):
+    print("""
+// AUTO GENERATED CODE - DO NOT EDIT
+
+/// Canonical compositions of a base character and a combining mark.
+///
+/// Covers the Latin, Greek and Cyrillic blocks. Sorted by base and mark.
+pub(crate) const COMPOSITIONS: &[(char, char, char)] = &[""", file=file)
+    ranges = [(0xC0, 0x24F), (0x370, 0x3FF), (0x400, 0x4FF),
+              (0x1E00, 0x1EFF), (0x1F00, 0x1FFF)]
+    rows = []
+    for start, end in ranges:
+        for cp in range(start, end + 1):
+            decomposition = unicodedata.decomposition(chr(cp))
+            if not decomposition or decomposition.startswith('<'):
+                continue
+            parts = [int(part, 16) for part in decomposition.split()]
+            if len(parts) != 2:
+                continue
+            # Skip composition exclusions.
+            if unicodedata.normalize('NFC', chr(parts[0]) + chr(parts[1])) != chr(cp):
+                continue
+            rows.append((parts[0], parts[1], cp))
+    rows.sort()
+    for base, mark, composed in rows:
+        print("    ('\\u{{{:X}}}', '\\u{{{:X}}}', '\\u{{{:X}}}'),".format(
+            base, mark, composed), file=file)
+    print("];", file=file)
+
+
+def convert_grapheme_extend(file):
+    print("""
+// AUTO GENERATED CODE - DO NOT EDIT
+
+/// Characters extending the preceding grapheme cluster.
+///
+/// These are the combining marks (general category M), which includes
+/// the Extend and SpacingMark sets of UAX #29 apart from a few
+/// exceptions, the emoji modifiers, the tags and the halfwidth
+/// katakana sound marks. Sorted ranges of inclusive bounds.
+pub(crate) const GRAPHEME_EXTEND: &[(char, char)] = &[""", file=file)
+    extra = set(range(0xFF9E, 0xFFA0)) | set(range(0x1F3FB, 0x1F400)) \
+        | set(range(0xE0020, 0xE0080))
+    ranges = []
+    for cp in range(0x110000):
+        if unicodedata.category(chr(cp)).startswith('M') or cp in extra:
+            if ranges and ranges[-1][1] == cp - 1:
+                ranges[-1][1] = cp
+            else:
+                ranges.append([cp, cp])
+    for start, end in ranges:
+        print("    ('\\u{{{:X}}}', '\\u{{{:X}}}'),".format(start, end), file=file)
+    print("];", file=file)
+
+
 if __name__ == '__main__':
     input = requests.get('https://w3c.github.io/uievents-key/').text
     with open('src/key.rs', 'w', encoding='utf-8') as output:
@@ -258,3 +337,7 @@ if __name__ == '__main__':
     input = requests.get('https://w3c.github.io/uievents-code/').text
     with open('src/code.rs', 'w', encoding='utf-8') as output:
         convert_code(input, output)
+    with open('src/unicode_table.rs', 'w', encoding='utf-8') as output:
+        convert_compositions(output)
+    with open('src/grapheme_table.rs', 'w', encoding='utf-8') as output:
+        convert_grapheme_extend(output)
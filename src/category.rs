@@ -0,0 +1,561 @@
+//! Classification of keys following the categories of the specification.
+//!
+//! Specification: <https://w3c.github.io/uievents-key/#named-key-attribute-values>
+
+use crate::{Code, Key};
+
+impl Key<'_> {
+    /// Check if the key is a navigation key.
+    ///
+    /// These are the arrow keys, Home, End, PageUp and PageDown.
+    ///
+    /// Specification: <https://w3c.github.io/uievents-key/#keys-navigation>
+    pub fn is_navigation(&self) -> bool {
+        matches!(
+            self,
+            Key::ArrowDown
+                | Key::ArrowLeft
+                | Key::ArrowRight
+                | Key::ArrowUp
+                | Key::End
+                | Key::Home
+                | Key::PageDown
+                | Key::PageUp
+        )
+    }
+
+    /// Check if the key is an editing key.
+    ///
+    /// These are Backspace, Delete, Insert and the clipboard and
+    /// undo keys among others.
+    ///
+    /// Specification: <https://w3c.github.io/uievents-key/#keys-editing>
+    pub fn is_editing(&self) -> bool {
+        matches!(
+            self,
+            Key::Backspace
+                | Key::Clear
+                | Key::Copy
+                | Key::CrSel
+                | Key::Cut
+                | Key::Delete
+                | Key::EraseEof
+                | Key::ExSel
+                | Key::Insert
+                | Key::Paste
+                | Key::Redo
+                | Key::Undo
+        )
+    }
+
+    /// Check if the key is a whitespace key.
+    ///
+    /// These are Enter, Tab and the space character.
+    ///
+    /// Specification: <https://w3c.github.io/uievents-key/#keys-whitespace>
+    pub fn is_whitespace(&self) -> bool {
+        matches!(self, Key::Enter | Key::Tab | Key::Character(" "))
+    }
+
+    /// Check if the key is a general purpose function key.
+    ///
+    /// These are the keys F1 to F24 and the soft keys Soft1 to Soft4.
+    ///
+    /// Specification: <https://w3c.github.io/uievents-key/#keys-function>
+    pub fn is_function(&self) -> bool {
+        matches!(
+            self,
+            Key::F1
+                | Key::F2
+                | Key::F3
+                | Key::F4
+                | Key::F5
+                | Key::F6
+                | Key::F7
+                | Key::F8
+                | Key::F9
+                | Key::F10
+                | Key::F11
+                | Key::F12
+                | Key::F13
+                | Key::F14
+                | Key::F15
+                | Key::F16
+                | Key::F17
+                | Key::F18
+                | Key::F19
+                | Key::F20
+                | Key::F21
+                | Key::F22
+                | Key::F23
+                | Key::F24
+                | Key::Soft1
+                | Key::Soft2
+                | Key::Soft3
+                | Key::Soft4
+        )
+    }
+
+    /// Check if the key is an IME or composition key.
+    ///
+    /// These are the keys to control input methods, like Convert or
+    /// the Korean and Japanese mode keys, and the Dead and Compose keys.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::Key;
+    /// assert!(Key::HangulMode.is_ime());
+    /// assert_eq!(Key::HangulMode.legacy_keycode(), 21);
+    /// assert!(!Key::Character("a").is_ime());
+    /// ```
+    ///
+    /// Specification: <https://w3c.github.io/uievents-key/#keys-composition>
+    pub fn is_ime(&self) -> bool {
+        matches!(
+            self,
+            Key::AllCandidates
+                | Key::Alphanumeric
+                | Key::CodeInput
+                | Key::Compose
+                | Key::Convert
+                | Key::Dead
+                | Key::FinalMode
+                | Key::GroupFirst
+                | Key::GroupLast
+                | Key::GroupNext
+                | Key::GroupPrevious
+                | Key::ModeChange
+                | Key::NextCandidate
+                | Key::NonConvert
+                | Key::PreviousCandidate
+                | Key::Process
+                | Key::SingleCandidate
+                | Key::HangulMode
+                | Key::HanjaMode
+                | Key::JunjaMode
+                | Key::Eisu
+                | Key::Hankaku
+                | Key::Hiragana
+                | Key::HiraganaKatakana
+                | Key::KanaMode
+                | Key::KanjiMode
+                | Key::Katakana
+                | Key::Romaji
+                | Key::Zenkaku
+                | Key::ZenkakuHankaku
+        )
+    }
+
+    /// Check if the key triggers an action of the operating system.
+    ///
+    /// These are the device keys like Power or Standby, the application
+    /// launcher keys, the browser keys and the media transport keys.
+    /// They are often handled before an application sees them and are
+    /// unsuitable for shortcuts.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::Key;
+    /// assert!(Key::Power.is_system_key());
+    /// assert!(Key::LaunchApplication1.is_system_key());
+    /// assert!(Key::MediaPlayPause.is_system_key());
+    /// assert!(!Key::Enter.is_system_key());
+    /// ```
+    ///
+    /// Specification: <https://w3c.github.io/uievents-key/#keys-device>,
+    /// <https://w3c.github.io/uievents-key/#keys-launch> and
+    /// <https://w3c.github.io/uievents-key/#keys-browser>
+    pub fn is_system_key(&self) -> bool {
+        matches!(
+            self,
+            Key::BrightnessDown
+                | Key::BrightnessUp
+                | Key::Eject
+                | Key::LogOff
+                | Key::Power
+                | Key::PowerOff
+                | Key::PrintScreen
+                | Key::Hibernate
+                | Key::Standby
+                | Key::WakeUp
+                | Key::LaunchApplication1
+                | Key::LaunchApplication2
+                | Key::LaunchCalendar
+                | Key::LaunchContacts
+                | Key::LaunchMail
+                | Key::LaunchMediaPlayer
+                | Key::LaunchMusicPlayer
+                | Key::LaunchPhone
+                | Key::LaunchScreenSaver
+                | Key::LaunchSpreadsheet
+                | Key::LaunchWebBrowser
+                | Key::LaunchWebCam
+                | Key::LaunchWordProcessor
+                | Key::BrowserBack
+                | Key::BrowserFavorites
+                | Key::BrowserForward
+                | Key::BrowserHome
+                | Key::BrowserRefresh
+                | Key::BrowserSearch
+                | Key::BrowserStop
+        ) || self.transport_action().is_some()
+    }
+
+    /// Check if holding the key auto-repeats it.
+    ///
+    /// Modifier and lock keys like Shift or CapsLock don't repeat,
+    /// all other keys do. Can be used to drop bogus `repeat` events
+    /// from synthetic event sources.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::Key;
+    /// assert!(Key::Character("a").repeats());
+    /// assert!(Key::ArrowLeft.repeats());
+    /// assert!(!Key::Shift.repeats());
+    /// assert!(!Key::CapsLock.repeats());
+    /// ```
+    pub fn repeats(&self) -> bool {
+        self.modifier_flag().is_none()
+    }
+}
+
+/// Media transport actions triggered by media keys.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TransportAction {
+    /// Start playback.
+    Play,
+    /// Pause playback.
+    Pause,
+    /// Toggle between playing and paused.
+    PlayPause,
+    /// Stop playback.
+    Stop,
+    /// Skip to the next track.
+    Next,
+    /// Go back to the previous track.
+    Previous,
+    /// Fast forward.
+    FastForward,
+    /// Rewind.
+    Rewind,
+    /// Increase the volume.
+    VolumeUp,
+    /// Decrease the volume.
+    VolumeDown,
+    /// Toggle the audio mute.
+    Mute,
+}
+
+impl Key<'_> {
+    /// Get the transport action of a media key.
+    ///
+    /// Keys without a transport meaning return `None`.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::{Key, TransportAction};
+    /// assert_eq!(Key::MediaPlayPause.transport_action(), Some(TransportAction::PlayPause));
+    /// assert_eq!(Key::AudioVolumeMute.transport_action(), Some(TransportAction::Mute));
+    /// assert_eq!(Key::Enter.transport_action(), None);
+    /// ```
+    ///
+    /// Specification: <https://w3c.github.io/uievents-key/#keys-multimedia>
+    pub fn transport_action(&self) -> Option<TransportAction> {
+        let action = match self {
+            Key::MediaPlay => TransportAction::Play,
+            Key::MediaPause => TransportAction::Pause,
+            Key::MediaPlayPause => TransportAction::PlayPause,
+            Key::MediaStop => TransportAction::Stop,
+            Key::MediaTrackNext => TransportAction::Next,
+            Key::MediaTrackPrevious => TransportAction::Previous,
+            Key::MediaFastForward => TransportAction::FastForward,
+            Key::MediaRewind => TransportAction::Rewind,
+            Key::AudioVolumeUp => TransportAction::VolumeUp,
+            Key::AudioVolumeDown => TransportAction::VolumeDown,
+            Key::AudioVolumeMute => TransportAction::Mute,
+            _ => return None,
+        };
+        Some(action)
+    }
+}
+
+impl Code {
+    /// Check if the code is one of the Enter keys.
+    ///
+    /// The main Enter key, labelled Return on Apple keyboards, and the
+    /// numpad Enter key have distinct codes but both produce `Key::Enter`.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::Code;
+    /// assert!(Code::Enter.is_enter());
+    /// assert!(Code::NumpadEnter.is_enter());
+    /// assert!(!Code::Space.is_enter());
+    /// ```
+    pub fn is_enter(&self) -> bool {
+        matches!(self, Code::Enter | Code::NumpadEnter)
+    }
+
+    /// Get the key produced by a numpad key depending on the NumLock state.
+    ///
+    /// With NumLock on the digit and decimal keys produce characters.
+    /// With NumLock off they act as navigation keys:
+    ///
+    /// | Code            | NumLock on | NumLock off  |
+    /// |-----------------|------------|--------------|
+    /// | `Numpad0`       | `0`        | `Insert`     |
+    /// | `Numpad1`       | `1`        | `End`        |
+    /// | `Numpad2`       | `2`        | `ArrowDown`  |
+    /// | `Numpad3`       | `3`        | `PageDown`   |
+    /// | `Numpad4`       | `4`        | `ArrowLeft`  |
+    /// | `Numpad5`       | `5`        | `Clear`      |
+    /// | `Numpad6`       | `6`        | `ArrowRight` |
+    /// | `Numpad7`       | `7`        | `Home`       |
+    /// | `Numpad8`       | `8`        | `ArrowUp`    |
+    /// | `Numpad9`       | `9`        | `PageUp`     |
+    /// | `NumpadDecimal` | `.`        | `Delete`     |
+    ///
+    /// The operator keys and `NumpadEnter` do not depend on NumLock.
+    /// The decimal separator is always `.` as it does not depend on the layout.
+    /// For codes outside the numpad `Key::Unidentified` is returned.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::{Code, Key};
+    /// assert_eq!(Code::Numpad1.numpad_key(true), Key::Character("1"));
+    /// assert_eq!(Code::Numpad1.numpad_key(false), Key::End);
+    /// ```
+    pub fn numpad_key(&self, num_lock: bool) -> Key<'static> {
+        match (self, num_lock) {
+            (Code::Numpad0, false) => Key::Insert,
+            (Code::Numpad1, false) => Key::End,
+            (Code::Numpad2, false) => Key::ArrowDown,
+            (Code::Numpad3, false) => Key::PageDown,
+            (Code::Numpad4, false) => Key::ArrowLeft,
+            (Code::Numpad5, false) => Key::Clear,
+            (Code::Numpad6, false) => Key::ArrowRight,
+            (Code::Numpad7, false) => Key::Home,
+            (Code::Numpad8, false) => Key::ArrowUp,
+            (Code::Numpad9, false) => Key::PageUp,
+            (Code::NumpadDecimal, false) => Key::Delete,
+            (Code::Numpad0, _) => Key::Character("0"),
+            (Code::Numpad1, _) => Key::Character("1"),
+            (Code::Numpad2, _) => Key::Character("2"),
+            (Code::Numpad3, _) => Key::Character("3"),
+            (Code::Numpad4, _) => Key::Character("4"),
+            (Code::Numpad5, _) => Key::Character("5"),
+            (Code::Numpad6, _) => Key::Character("6"),
+            (Code::Numpad7, _) => Key::Character("7"),
+            (Code::Numpad8, _) => Key::Character("8"),
+            (Code::Numpad9, _) => Key::Character("9"),
+            (Code::NumpadDecimal, _) => Key::Character("."),
+            (Code::NumpadAdd, _) => Key::Character("+"),
+            (Code::NumpadComma, _) => Key::Character(","),
+            (Code::NumpadDivide, _) => Key::Character("/"),
+            (Code::NumpadEqual, _) => Key::Character("="),
+            (Code::NumpadMultiply, _) | (Code::NumpadStar, _) => Key::Character("*"),
+            (Code::NumpadSubtract, _) => Key::Character("-"),
+            (Code::NumpadHash, _) => Key::Character("#"),
+            (Code::NumpadParenLeft, _) => Key::Character("("),
+            (Code::NumpadParenRight, _) => Key::Character(")"),
+            (Code::NumpadEnter, _) => Key::Enter,
+            (Code::NumpadBackspace, _) => Key::Backspace,
+            (Code::NumpadClear, _) | (Code::NumpadClearEntry, _) => Key::Clear,
+            _ => Key::Unidentified(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Layout, Location, Modifiers, UsQwerty};
+
+    #[test]
+    fn test_enter() {
+        assert_ne!(Code::Enter, Code::NumpadEnter);
+        for code in &[Code::Enter, Code::NumpadEnter] {
+            assert!(code.is_enter());
+            assert_eq!(UsQwerty.resolve(*code, Modifiers::empty()), Key::Enter);
+        }
+        assert_eq!(Location::from_code(Code::NumpadEnter), Location::Numpad);
+        assert!(Key::Enter.is_whitespace());
+    }
+
+    #[test]
+    fn test_ime() {
+        let keycodes = [
+            (Key::HangulMode, 21),
+            (Key::HanjaMode, 25),
+            (Key::KanaMode, 21),
+            (Key::Convert, 28),
+            (Key::NonConvert, 29),
+            (Key::Alphanumeric, 240),
+            (Key::Katakana, 241),
+            (Key::Hiragana, 242),
+        ];
+        for (key, keycode) in &keycodes {
+            assert!(key.is_ime());
+            assert_eq!(key.legacy_keycode(), *keycode);
+        }
+        assert!(!Key::Escape.is_ime());
+    }
+
+    #[test]
+    fn test_categories() {
+        let navigation = [
+            Key::ArrowDown,
+            Key::ArrowLeft,
+            Key::ArrowRight,
+            Key::ArrowUp,
+            Key::End,
+            Key::Home,
+            Key::PageDown,
+            Key::PageUp,
+        ];
+        let editing = [
+            Key::Backspace,
+            Key::Clear,
+            Key::Copy,
+            Key::CrSel,
+            Key::Cut,
+            Key::Delete,
+            Key::EraseEof,
+            Key::ExSel,
+            Key::Insert,
+            Key::Paste,
+            Key::Redo,
+            Key::Undo,
+        ];
+        let whitespace = [Key::Enter, Key::Tab, Key::Character(" ")];
+        let function = [
+            Key::F1,
+            Key::F2,
+            Key::F3,
+            Key::F4,
+            Key::F5,
+            Key::F6,
+            Key::F7,
+            Key::F8,
+            Key::F9,
+            Key::F10,
+            Key::F11,
+            Key::F12,
+            Key::F13,
+            Key::F14,
+            Key::F15,
+            Key::F16,
+            Key::F17,
+            Key::F18,
+            Key::F19,
+            Key::F20,
+            Key::F21,
+            Key::F22,
+            Key::F23,
+            Key::F24,
+            Key::Soft1,
+            Key::Soft2,
+            Key::Soft3,
+            Key::Soft4,
+        ];
+        let ime = [
+            Key::AllCandidates,
+            Key::Alphanumeric,
+            Key::CodeInput,
+            Key::Compose,
+            Key::Convert,
+            Key::Dead,
+            Key::FinalMode,
+            Key::GroupFirst,
+            Key::GroupLast,
+            Key::GroupNext,
+            Key::GroupPrevious,
+            Key::ModeChange,
+            Key::NextCandidate,
+            Key::NonConvert,
+            Key::PreviousCandidate,
+            Key::Process,
+            Key::SingleCandidate,
+            Key::HangulMode,
+            Key::HanjaMode,
+            Key::JunjaMode,
+            Key::Eisu,
+            Key::Hankaku,
+            Key::Hiragana,
+            Key::HiraganaKatakana,
+            Key::KanaMode,
+            Key::KanjiMode,
+            Key::Katakana,
+            Key::Romaji,
+            Key::Zenkaku,
+            Key::ZenkakuHankaku,
+        ];
+        let system = [
+            Key::BrightnessDown,
+            Key::BrightnessUp,
+            Key::Eject,
+            Key::LogOff,
+            Key::Power,
+            Key::PowerOff,
+            Key::PrintScreen,
+            Key::Hibernate,
+            Key::Standby,
+            Key::WakeUp,
+            Key::LaunchApplication1,
+            Key::LaunchApplication2,
+            Key::LaunchCalendar,
+            Key::LaunchContacts,
+            Key::LaunchMail,
+            Key::LaunchMediaPlayer,
+            Key::LaunchMusicPlayer,
+            Key::LaunchPhone,
+            Key::LaunchScreenSaver,
+            Key::LaunchSpreadsheet,
+            Key::LaunchWebBrowser,
+            Key::LaunchWebCam,
+            Key::LaunchWordProcessor,
+            Key::BrowserBack,
+            Key::BrowserFavorites,
+            Key::BrowserForward,
+            Key::BrowserHome,
+            Key::BrowserRefresh,
+            Key::BrowserSearch,
+            Key::BrowserStop,
+            Key::MediaPlay,
+            Key::MediaPause,
+            Key::MediaPlayPause,
+            Key::MediaStop,
+            Key::MediaTrackNext,
+            Key::MediaTrackPrevious,
+            Key::MediaFastForward,
+            Key::MediaRewind,
+            Key::AudioVolumeUp,
+            Key::AudioVolumeDown,
+            Key::AudioVolumeMute,
+        ];
+        let others = [
+            Key::Character("a"),
+            Key::Character("  "),
+            Key::Unidentified(None),
+            Key::Escape,
+            Key::Shift,
+            Key::ContextMenu,
+            Key::MediaRecord,
+            Key::Help,
+        ];
+        type Predicate = fn(&Key<'static>) -> bool;
+        let categories: [(&[Key<'static>], Predicate); 6] = [
+            (&navigation, Key::is_navigation),
+            (&editing, Key::is_editing),
+            (&whitespace, Key::is_whitespace),
+            (&function, Key::is_function),
+            (&ime, Key::is_ime),
+            (&system, Key::is_system_key),
+        ];
+        let all = categories
+            .iter()
+            .flat_map(|(keys, _)| keys.iter())
+            .chain(others.iter());
+        for key in all {
+            for (keys, predicate) in &categories {
+                assert_eq!(predicate(key), keys.contains(key), "{:?}", key);
+            }
+        }
+    }
+}
@@ -0,0 +1,321 @@
+//! Validation of `Key::Character` values.
+
+use core::cmp::Ordering;
+use core::fmt;
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::grapheme_table::GRAPHEME_EXTEND;
+use crate::Key;
+
+/// Error returned when a string is not a valid `Key::Character` value.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum KeyError {
+    /// The string is empty.
+    Empty,
+
+    /// The string contains a control or format character.
+    NotPrintable,
+
+    /// The string contains more than one grapheme cluster.
+    MultipleGraphemes,
+}
+
+impl fmt::Display for KeyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KeyError::Empty => f.write_str("Empty key character"),
+            KeyError::NotPrintable => f.write_str("Key character is not printable"),
+            KeyError::MultipleGraphemes => f.write_str("Key character has multiple graphemes"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for KeyError {}
+
+impl<'a> Key<'a> {
+    /// Create a `Key::Character` after validating the string.
+    ///
+    /// The string must be a single grapheme cluster of printable
+    /// characters. Control characters and invisible format characters
+    /// are rejected.
+    ///
+    /// Grapheme clusters follow the extended grapheme clusters of
+    /// UAX #29 with a few simplifications: a base character may be
+    /// followed by combining marks of any script, including spacing marks
+    /// like the Devanagari vowel signs, variation selectors, emoji
+    /// modifiers and tags. Hangul jamo combine into syllables. Any
+    /// character following a zero width joiner is joined, not just
+    /// emoji. A pair of regional indicators forms a flag. Prepended
+    /// characters are not supported and a cluster must not start with
+    /// a combining mark.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::{Key, KeyError};
+    /// assert_eq!(Key::character("a"), Ok(Key::Character("a")));
+    /// assert_eq!(Key::character("n\u{303}"), Ok(Key::Character("n\u{303}")));
+    /// assert_eq!(Key::character(""), Err(KeyError::Empty));
+    /// assert_eq!(Key::character("ab"), Err(KeyError::MultipleGraphemes));
+    /// ```
+    pub fn character(s: &'a str) -> Result<Key<'a>, KeyError> {
+        validate_character(s)?;
+        Ok(Key::Character(s))
+    }
+
+    /// Create a `Key::Character` without validating the string.
+    ///
+    /// Use this in hot paths where the string is known to be valid,
+    /// e.g. when it comes from a layout table.
+    /// See [`Key::character`] for the invariant the string should uphold.
+    pub fn character_unchecked(s: &'a str) -> Key<'a> {
+        Key::Character(s)
+    }
+
+    /// Create the key produced by the space bar.
+    ///
+    /// The specification has no named key for space, it is the
+    /// character value `" "`. There is no `Key::Space` and
+    /// `Key::parse("Space")` fails.
+    pub fn space() -> Key<'static> {
+        Key::Character(" ")
+    }
+
+    /// Check if the key is the space character.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::Key;
+    /// assert!(Key::Character(" ").is_space());
+    /// assert!(!Key::Character("\u{A0}").is_space());
+    /// ```
+    pub fn is_space(&self) -> bool {
+        *self == Key::Character(" ")
+    }
+
+    /// Create a `Key::Character` from a single `char`.
+    ///
+    /// As `Key` borrows its character value the `char` is encoded into
+    /// the provided buffer. This is useful to convert events from
+    /// libraries which report characters as `char`, like terminal
+    /// input libraries.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::Key;
+    /// let mut buf = [0; 4];
+    /// assert_eq!(Key::from_char('ß', &mut buf), Key::Character("ß"));
+    /// ```
+    pub fn from_char(c: char, buf: &'a mut [u8; 4]) -> Key<'a> {
+        Key::Character(c.encode_utf8(buf))
+    }
+
+    /// Get the ASCII byte produced by the key.
+    ///
+    /// Character keys return their byte if the value is a single ASCII
+    /// character. The whitespace and control keys return their control
+    /// codes:
+    ///
+    /// | Key         | Byte   |
+    /// |-------------|--------|
+    /// | `Backspace` | `0x08` |
+    /// | `Tab`       | `0x09` |
+    /// | `Enter`     | `0x0D` |
+    /// | `Escape`    | `0x1B` |
+    ///
+    /// All other keys return `None`.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::Key;
+    /// assert_eq!(Key::Character("a").as_ascii_byte(), Some(b'a'));
+    /// assert_eq!(Key::Enter.as_ascii_byte(), Some(b'\r'));
+    /// assert_eq!(Key::Character("ä").as_ascii_byte(), None);
+    /// ```
+    pub fn as_ascii_byte(&self) -> Option<u8> {
+        match self {
+            Key::Character(s) if s.len() == 1 && s.is_ascii() => Some(s.as_bytes()[0]),
+            Key::Backspace => Some(0x08),
+            Key::Tab => Some(0x09),
+            Key::Enter => Some(0x0D),
+            Key::Escape => Some(0x1B),
+            _ => None,
+        }
+    }
+}
+
+fn validate_character(s: &str) -> Result<(), KeyError> {
+    let mut chars = s.chars();
+    let first = chars.next().ok_or(KeyError::Empty)?;
+    if !is_printable(first) {
+        return Err(KeyError::NotPrintable);
+    }
+    if is_extender(first) || first == ZERO_WIDTH_JOINER {
+        return Err(KeyError::MultipleGraphemes);
+    }
+    let mut previous = first;
+    let mut regional_indicators = if is_regional_indicator(first) { 1 } else { 0 };
+    for c in chars {
+        if !is_printable(c) && c != ZERO_WIDTH_JOINER {
+            return Err(KeyError::NotPrintable);
+        }
+        let joined = previous == ZERO_WIDTH_JOINER && c != ZERO_WIDTH_JOINER;
+        if is_regional_indicator(c) && regional_indicators == 1 {
+            regional_indicators += 1;
+        } else if !(joined || is_extender(c) || c == ZERO_WIDTH_JOINER || hangul_joins(previous, c))
+        {
+            return Err(KeyError::MultipleGraphemes);
+        }
+        previous = c;
+    }
+    if previous == ZERO_WIDTH_JOINER {
+        return Err(KeyError::MultipleGraphemes);
+    }
+    Ok(())
+}
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+/// Check that a character is neither a control nor a format character.
+fn is_printable(c: char) -> bool {
+    !c.is_control()
+        && !matches!(c,
+            '\u{AD}'
+            | '\u{600}'..='\u{605}'
+            | '\u{61C}'
+            | '\u{180E}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{2066}'..='\u{206F}'
+            | '\u{FEFF}'
+            | '\u{FFF9}'..='\u{FFFB}'
+        )
+}
+
+/// Check if a character extends the preceding grapheme cluster.
+fn is_extender(c: char) -> bool {
+    GRAPHEME_EXTEND
+        .binary_search_by(|(start, end)| {
+            if *end < c {
+                Ordering::Less
+            } else if *start > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// The Hangul syllable types of UAX #29.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Hangul {
+    /// Leading consonant.
+    L,
+    /// Vowel.
+    V,
+    /// Trailing consonant.
+    T,
+    /// Syllable without trailing consonant.
+    Lv,
+    /// Syllable with trailing consonant.
+    Lvt,
+}
+
+fn hangul(c: char) -> Option<Hangul> {
+    match c {
+        '\u{1100}'..='\u{115F}' | '\u{A960}'..='\u{A97C}' => Some(Hangul::L),
+        '\u{1160}'..='\u{11A7}' | '\u{D7B0}'..='\u{D7C6}' => Some(Hangul::V),
+        '\u{11A8}'..='\u{11FF}' | '\u{D7CB}'..='\u{D7FB}' => Some(Hangul::T),
+        '\u{AC00}'..='\u{D7A3}' if (c as u32 - 0xAC00) % 28 == 0 => Some(Hangul::Lv),
+        '\u{AC00}'..='\u{D7A3}' => Some(Hangul::Lvt),
+        _ => None,
+    }
+}
+
+/// Check if two Hangul characters belong to the same syllable.
+fn hangul_joins(previous: char, c: char) -> bool {
+    use Hangul::*;
+    matches!(
+        (hangul(previous), hangul(c)),
+        (Some(L), Some(L))
+            | (Some(L), Some(V))
+            | (Some(L), Some(Lv))
+            | (Some(L), Some(Lvt))
+            | (Some(V), Some(V))
+            | (Some(V), Some(T))
+            | (Some(Lv), Some(V))
+            | (Some(Lv), Some(T))
+            | (Some(T), Some(T))
+            | (Some(Lvt), Some(T))
+    )
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    matches!(c, '\u{1F1E6}'..='\u{1F1FF}')
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_validate_character() {
+        assert_eq!(validate_character("A"), Ok(()));
+        assert_eq!(validate_character("ñ"), Ok(()));
+        assert_eq!(validate_character("e\u{301}"), Ok(()));
+        assert_eq!(validate_character("👍🏽"), Ok(()));
+        assert_eq!(validate_character("👩\u{200D}💻"), Ok(()));
+        assert_eq!(validate_character("🇩🇪"), Ok(()));
+        assert_eq!(validate_character(""), Err(KeyError::Empty));
+        assert_eq!(validate_character("\t"), Err(KeyError::NotPrintable));
+        assert_eq!(validate_character("\u{200B}"), Err(KeyError::NotPrintable));
+        assert_eq!(validate_character("AB"), Err(KeyError::MultipleGraphemes));
+        assert_eq!(
+            validate_character("\u{301}"),
+            Err(KeyError::MultipleGraphemes)
+        );
+        assert_eq!(validate_character("🇩🇪🇫"), Err(KeyError::MultipleGraphemes));
+        assert_eq!(
+            validate_character("a\u{200D}"),
+            Err(KeyError::MultipleGraphemes)
+        );
+    }
+
+    #[test]
+    fn test_validate_scripts() {
+        // Devanagari spacing vowel sign.
+        assert_eq!(validate_character("\u{915}\u{93F}"), Ok(()));
+        // Thai tone mark.
+        assert_eq!(validate_character("\u{E01}\u{E48}"), Ok(()));
+        // Japanese voiced sound marks, combining and halfwidth.
+        assert_eq!(validate_character("\u{304B}\u{3099}"), Ok(()));
+        assert_eq!(validate_character("\u{FF76}\u{FF9E}"), Ok(()));
+        // Hangul jamo and syllables.
+        assert_eq!(validate_character("\u{1100}\u{1161}\u{11A8}"), Ok(()));
+        assert_eq!(validate_character("\u{AC00}\u{11A8}"), Ok(()));
+        assert_eq!(validate_character("\u{AC01}"), Ok(()));
+        assert_eq!(
+            validate_character("\u{AC01}\u{1161}"),
+            Err(KeyError::MultipleGraphemes)
+        );
+        assert_eq!(
+            validate_character("\u{11A8}\u{1100}"),
+            Err(KeyError::MultipleGraphemes)
+        );
+        assert_eq!(
+            validate_character("\u{915}\u{915}"),
+            Err(KeyError::MultipleGraphemes)
+        );
+        for pair in GRAPHEME_EXTEND.windows(2) {
+            assert!(pair[0].0 <= pair[0].1 && pair[0].1 < pair[1].0);
+        }
+    }
+
+    #[test]
+    fn test_space() {
+        assert!(Key::space().is_space());
+        assert!(Key::space().is_whitespace());
+        assert_eq!(Key::space().legacy_keycode(), 32);
+        assert!(Key::parse("Space").is_err());
+    }
+}
@@ -0,0 +1,235 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+use crate::{Key, KeyMatch, KeyPattern, KeyboardEvent};
+
+/// A set of shortcuts indexed by key for fast matching.
+///
+/// Built once from a list of bindings, each a [`KeyPattern`] and a value.
+/// Looking up an event only tests the patterns for its key and those
+/// matching any key, instead of every pattern like the
+/// [`ShortcutMatcher`](crate::ShortcutMatcher) does. With 500 bindings
+/// this is about 30 times faster, see `benches/shortcuts.rs`.
+///
+/// If several patterns match an event the first binding in the list wins.
+///
+/// ```rust
+/// # use gluten_keyboard::{CompiledShortcuts, Key, KeyboardEvent, KeyMatch, KeyPattern};
+/// # use gluten_keyboard::{ModifierMatch, Modifiers};
+/// let shortcuts: CompiledShortcuts<&str> = vec![
+///     (
+///         KeyPattern {
+///             modifiers: ModifierMatch::Exact(Modifiers::CONTROL),
+///             key: KeyMatch::Is(Key::Character("s")),
+///         },
+///         "save",
+///     ),
+///     (KeyPattern::ANY, "insert"),
+/// ]
+/// .into_iter()
+/// .collect();
+/// let event = KeyboardEvent {
+///     key: Key::Character("S"),
+///     modifiers: Modifiers::CONTROL,
+///     ..Default::default()
+/// };
+/// assert_eq!(shortcuts.match_event(&event), Some(&"save"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CompiledShortcuts<'a, T> {
+    bindings: Vec<(KeyPattern<'a>, T)>,
+    buckets: BTreeMap<Bucket, Vec<usize>>,
+    /// Bindings matching more than a single key.
+    wildcards: Vec<usize>,
+}
+
+/// The index of a key.
+///
+/// Characters are indexed by their first character ignoring ASCII case,
+/// or NUL if empty, all other keys by their telemetry name.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum Bucket {
+    Char(char),
+    Named(&'static str),
+}
+
+impl Bucket {
+    fn new(key: &Key) -> Bucket {
+        match key {
+            Key::Character(s) => {
+                Bucket::Char(s.chars().next().unwrap_or('\0').to_ascii_lowercase())
+            }
+            key => Bucket::Named(key.telemetry_name()),
+        }
+    }
+}
+
+impl<'a, T> CompiledShortcuts<'a, T> {
+    /// Index the bindings.
+    pub fn new<I>(bindings: I) -> CompiledShortcuts<'a, T>
+    where
+        I: IntoIterator<Item = (KeyPattern<'a>, T)>,
+    {
+        let bindings: Vec<_> = bindings.into_iter().collect();
+        let mut buckets: BTreeMap<Bucket, Vec<usize>> = BTreeMap::new();
+        let mut wildcards = Vec::new();
+        for (index, (pattern, _)) in bindings.iter().enumerate() {
+            match pattern.key {
+                KeyMatch::Is(key) => buckets.entry(Bucket::new(&key)).or_default().push(index),
+                KeyMatch::AnyChar | KeyMatch::Any => wildcards.push(index),
+            }
+        }
+        CompiledShortcuts {
+            bindings,
+            buckets,
+            wildcards,
+        }
+    }
+
+    /// Find the value of the first binding matching the event.
+    ///
+    /// Like [`KeyPattern::matches`] only key presses are matched.
+    pub fn match_event(&self, event: &KeyboardEvent) -> Option<&T> {
+        let matches = |index: &&usize| self.bindings[**index].0.matches(event);
+        let indexed = self
+            .buckets
+            .get(&Bucket::new(&event.key))
+            .and_then(|indices| indices.iter().find(matches));
+        let wildcard = self.wildcards.iter().find(matches);
+        let index = match (indexed, wildcard) {
+            (Some(a), Some(b)) => a.min(b),
+            (a, b) => a.or(b)?,
+        };
+        Some(&self.bindings[*index].1)
+    }
+}
+
+impl<'a, T> FromIterator<(KeyPattern<'a>, T)> for CompiledShortcuts<'a, T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (KeyPattern<'a>, T)>,
+    {
+        CompiledShortcuts::new(iter)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec;
+
+    use super::*;
+    use crate::{KeyState, ModifierMatch, Modifiers};
+
+    #[test]
+    fn test_match_event() {
+        let pattern = |modifiers, key| KeyPattern {
+            modifiers: ModifierMatch::Exact(modifiers),
+            key: KeyMatch::Is(key),
+        };
+        let shortcuts = CompiledShortcuts::new(vec![
+            (pattern(Modifiers::CONTROL, Key::Character("x")), 0),
+            (
+                KeyPattern {
+                    modifiers: ModifierMatch::AtLeast(Modifiers::CONTROL),
+                    key: KeyMatch::AnyChar,
+                },
+                1,
+            ),
+            (pattern(Modifiers::CONTROL, Key::Character("y")), 2),
+            (pattern(Modifiers::empty(), Key::Character("")), 3),
+            (pattern(Modifiers::empty(), Key::Enter), 4),
+        ]);
+        let event = |key, modifiers| KeyboardEvent {
+            key,
+            modifiers,
+            ..Default::default()
+        };
+        let ctrl = Modifiers::CONTROL | Modifiers::NUM_LOCK;
+        assert_eq!(
+            shortcuts.match_event(&event(Key::Character("X"), ctrl)),
+            Some(&0)
+        );
+        assert_eq!(
+            shortcuts.match_event(&event(Key::Character("y"), ctrl)),
+            Some(&1)
+        );
+        assert_eq!(shortcuts.match_event(&event(Key::Enter, ctrl)), None);
+        let mut enter = event(Key::Enter, Modifiers::empty());
+        assert_eq!(shortcuts.match_event(&enter), Some(&4));
+        enter.state = KeyState::Up;
+        assert_eq!(shortcuts.match_event(&enter), None);
+        let empty = event(Key::Character(""), Modifiers::empty());
+        assert_eq!(shortcuts.match_event(&empty), Some(&3));
+        let nul = event(Key::Character("\0"), Modifiers::empty());
+        assert_eq!(shortcuts.match_event(&nul), None);
+    }
+
+    #[test]
+    fn test_linear_equivalence() {
+        let keys = [
+            Key::Character("a"),
+            Key::Character("A"),
+            Key::Character("b"),
+            Key::Character("ab"),
+            Key::Character("é"),
+            Key::Character(""),
+            Key::Enter,
+            Key::F1,
+            Key::Shift,
+            Key::Unidentified(None),
+            Key::Unidentified(Some(7)),
+        ];
+        let modifiers = [
+            Modifiers::empty(),
+            Modifiers::CONTROL,
+            Modifiers::CONTROL | Modifiers::SHIFT,
+            Modifiers::ALT | Modifiers::CAPS_LOCK,
+        ];
+        let mut bindings = vec![];
+        for (i, key) in keys.iter().enumerate() {
+            let key_match = match i % 5 {
+                3 => KeyMatch::AnyChar,
+                4 if i > 5 => KeyMatch::Any,
+                _ => KeyMatch::Is(*key),
+            };
+            let modifier_match = match i % 3 {
+                0 => ModifierMatch::Exact(modifiers[i % modifiers.len()]),
+                1 => ModifierMatch::AtLeast(modifiers[i % modifiers.len()]),
+                _ => ModifierMatch::Any,
+            };
+            bindings.push((
+                KeyPattern {
+                    modifiers: modifier_match,
+                    key: key_match,
+                },
+                i,
+            ));
+            bindings.push((
+                KeyPattern {
+                    modifiers: ModifierMatch::Exact(Modifiers::CONTROL),
+                    key: KeyMatch::Is(*key),
+                },
+                100 + i,
+            ));
+        }
+        let shortcuts = CompiledShortcuts::new(bindings.clone());
+        for key in keys.iter() {
+            for modifiers in modifiers.iter() {
+                for state in [KeyState::Down, KeyState::Up].iter() {
+                    let event = KeyboardEvent {
+                        state: *state,
+                        key: *key,
+                        modifiers: *modifiers,
+                        ..Default::default()
+                    };
+                    let linear = bindings
+                        .iter()
+                        .find(|(pattern, _)| pattern.matches(&event))
+                        .map(|(_, value)| value);
+                    assert_eq!(shortcuts.match_event(&event), linear, "{:?}", event);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Combinations of a dead key accent with a base character.
+///
+/// The accents are the spacing characters of the dead keys,
+/// like `´` for the acute accent. Tables are built from a map of
+/// `(accent, base)` pairs or from the built-in set of a locale.
+///
+/// ```rust
+/// # use gluten_keyboard::ComposeTable;
+/// let table = ComposeTable::for_locale("pt-BR").unwrap();
+/// assert_eq!(table.compose('~', 'a'), Some('ã'));
+/// assert_eq!(table.compose('´', 'c'), Some('ç'));
+/// assert_eq!(table.compose('~', 'x'), None);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ComposeTable {
+    entries: BTreeMap<(char, char), char>,
+}
+
+impl ComposeTable {
+    /// Create an empty table.
+    pub fn new() -> ComposeTable {
+        ComposeTable::default()
+    }
+
+    /// Create the built-in table for a locale identifier like `"de-CH"`.
+    ///
+    /// Only the language is taken into account. The supported languages
+    /// and the accents of their dead keys are:
+    ///
+    /// | Language        | Accents                                  |
+    /// |-----------------|------------------------------------------|
+    /// | `de` German     | `^` `´` `` ` ``                          |
+    /// | `fr` French     | `^` `¨` `´` `` ` ``                      |
+    /// | `es` Spanish    | `´` `¨` `` ` `` `^`, and `~` on n        |
+    /// | `pt` Portuguese | `´` `` ` `` `^` `~` `¨`, and `´` on c    |
+    /// | `nl` Dutch      | `´` `` ` `` `^` `~` `¨`                  |
+    ///
+    /// Each accent combines with the vowels, and with space to give the
+    /// accent itself. Returns `None` for other languages.
+    pub fn for_locale(locale: &str) -> Option<ComposeTable> {
+        let language = locale.split(|c| c == '-' || c == '_').next()?;
+        let (accents, extra): (&[char], &[(char, char, char)]) =
+            match language.to_ascii_lowercase().as_str() {
+                "de" => (&['^', '´', '`'], &[]),
+                "fr" => (&['^', '¨', '´', '`'], &[]),
+                "es" => (&['´', '¨', '`', '^'], &[('~', 'n', 'ñ'), ('~', 'N', 'Ñ')]),
+                "pt" => (
+                    &['´', '`', '^', '~', '¨'],
+                    &[('´', 'c', 'ç'), ('´', 'C', 'Ç')],
+                ),
+                "nl" => (&['´', '`', '^', '~', '¨'], &[]),
+                _ => return None,
+            };
+        let mut table = ComposeTable::new();
+        for (accent, base, result) in LATIN {
+            if accents.contains(accent) {
+                table.insert(*accent, *base, *result);
+            }
+        }
+        for (accent, base, result) in extra {
+            table.insert(*accent, *base, *result);
+        }
+        for accent in accents
+            .iter()
+            .chain(extra.iter().map(|(accent, _, _)| accent))
+        {
+            table.insert(*accent, ' ', *accent);
+        }
+        Some(table)
+    }
+
+    /// Add a combination, replacing an existing one.
+    pub fn insert(&mut self, accent: char, base: char, result: char) {
+        self.entries.insert((accent, base), result);
+    }
+
+    /// Combine the accent with the base character.
+    ///
+    /// Returns `None` if the table has no such combination.
+    pub fn compose(&self, accent: char, base: char) -> Option<char> {
+        self.entries.get(&(accent, base)).copied()
+    }
+}
+
+impl From<BTreeMap<(char, char), char>> for ComposeTable {
+    fn from(entries: BTreeMap<(char, char), char>) -> Self {
+        ComposeTable { entries }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<HashMap<(char, char), char>> for ComposeTable {
+    fn from(entries: HashMap<(char, char), char>) -> Self {
+        ComposeTable {
+            entries: entries.into_iter().collect(),
+        }
+    }
+}
+
+/// Accented Latin vowels shared by the built-in locales.
+const LATIN: &[(char, char, char)] = &[
+    ('´', 'a', 'á'),
+    ('´', 'e', 'é'),
+    ('´', 'i', 'í'),
+    ('´', 'o', 'ó'),
+    ('´', 'u', 'ú'),
+    ('´', 'y', 'ý'),
+    ('´', 'A', 'Á'),
+    ('´', 'E', 'É'),
+    ('´', 'I', 'Í'),
+    ('´', 'O', 'Ó'),
+    ('´', 'U', 'Ú'),
+    ('´', 'Y', 'Ý'),
+    ('`', 'a', 'à'),
+    ('`', 'e', 'è'),
+    ('`', 'i', 'ì'),
+    ('`', 'o', 'ò'),
+    ('`', 'u', 'ù'),
+    ('`', 'A', 'À'),
+    ('`', 'E', 'È'),
+    ('`', 'I', 'Ì'),
+    ('`', 'O', 'Ò'),
+    ('`', 'U', 'Ù'),
+    ('^', 'a', 'â'),
+    ('^', 'e', 'ê'),
+    ('^', 'i', 'î'),
+    ('^', 'o', 'ô'),
+    ('^', 'u', 'û'),
+    ('^', 'A', 'Â'),
+    ('^', 'E', 'Ê'),
+    ('^', 'I', 'Î'),
+    ('^', 'O', 'Ô'),
+    ('^', 'U', 'Û'),
+    ('~', 'a', 'ã'),
+    ('~', 'n', 'ñ'),
+    ('~', 'o', 'õ'),
+    ('~', 'A', 'Ã'),
+    ('~', 'N', 'Ñ'),
+    ('~', 'O', 'Õ'),
+    ('¨', 'a', 'ä'),
+    ('¨', 'e', 'ë'),
+    ('¨', 'i', 'ï'),
+    ('¨', 'o', 'ö'),
+    ('¨', 'u', 'ü'),
+    ('¨', 'y', 'ÿ'),
+    ('¨', 'A', 'Ä'),
+    ('¨', 'E', 'Ë'),
+    ('¨', 'I', 'Ï'),
+    ('¨', 'O', 'Ö'),
+    ('¨', 'U', 'Ü'),
+    ('¨', 'Y', 'Ÿ'),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_locales() {
+        let es = ComposeTable::for_locale("es_MX").unwrap();
+        assert_eq!(es.compose('~', 'n'), Some('ñ'));
+        assert_eq!(es.compose('~', 'a'), None);
+        assert_eq!(es.compose('~', ' '), Some('~'));
+        let pt = ComposeTable::for_locale("PT").unwrap();
+        assert_eq!(pt.compose('~', 'a'), Some('ã'));
+        let de = ComposeTable::for_locale("de-DE").unwrap();
+        assert_eq!(de.compose('^', 'o'), Some('ô'));
+        assert_eq!(de.compose('¨', 'o'), None);
+        assert!(ComposeTable::for_locale("ja").is_none());
+
+        let mut map = BTreeMap::new();
+        map.insert(('˘', 'a'), 'ă');
+        let custom = ComposeTable::from(map);
+        assert_eq!(custom.compose('˘', 'a'), Some('ă'));
+        #[cfg(feature = "std")]
+        {
+            let mut map = HashMap::new();
+            map.insert(('˚', 'a'), 'å');
+            assert_eq!(ComposeTable::from(map).compose('˚', 'a'), Some('å'));
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{CompositionEvent, CompositionState};
+
+/// Builder for correctly ordered composition events.
+///
+/// A composition session is a "compositionstart" event, followed by
+/// zero or more "compositionupdate" events and a "compositionend" event.
+/// In debug builds emitting the events out of order panics.
+///
+/// ```rust
+/// # use gluten_keyboard::{CompositionSession, CompositionState};
+/// let mut session = CompositionSession::new();
+/// assert_eq!(session.start("").state, CompositionState::Start);
+/// session.update("´");
+/// assert!(session.is_active());
+/// let end = session.end("é");
+/// assert_eq!(end.data, "é");
+/// assert!(!session.is_active());
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CompositionSession {
+    active: bool,
+}
+
+impl CompositionSession {
+    /// Create a builder with no active composition.
+    pub fn new() -> CompositionSession {
+        CompositionSession { active: false }
+    }
+
+    /// Check if a composition was started and not ended yet.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Start a composition.
+    ///
+    /// Panics in debug builds if a composition is already active.
+    pub fn start<'a>(&mut self, data: &'a str) -> CompositionEvent<'a> {
+        debug_assert!(!self.active, "composition started twice");
+        self.active = true;
+        CompositionEvent {
+            state: CompositionState::Start,
+            data,
+        }
+    }
+
+    /// Update the active composition.
+    ///
+    /// Panics in debug builds if no composition is active.
+    pub fn update<'a>(&mut self, data: &'a str) -> CompositionEvent<'a> {
+        debug_assert!(self.active, "composition updated before start");
+        CompositionEvent {
+            state: CompositionState::Update,
+            data,
+        }
+    }
+
+    /// End the active composition with the committed data.
+    ///
+    /// Panics in debug builds if no composition is active.
+    pub fn end<'a>(&mut self, data: &'a str) -> CompositionEvent<'a> {
+        debug_assert!(self.active, "composition ended before start");
+        self.active = false;
+        CompositionEvent {
+            state: CompositionState::End,
+            data,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_end_twice() {
+        let mut session = CompositionSession::new();
+        session.start("");
+        session.end("a");
+        session.end("a");
+    }
+}
@@ -0,0 +1,433 @@
+//! Convert between crossterm and gluten-keyboard events.
+
+use core::convert::TryFrom;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crossterm::event::{
+    KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MediaKeyCode, ModifierKeyCode,
+};
+
+use crate::{Code, Key, KeyState, KeyboardEvent, Location, Modifiers};
+
+/// The printable ASCII characters, sliced for characters of `'static` events.
+const PRINTABLE: &str = " !\"#$%&'()*+,-./0123456789:;<=>?@\
+    ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
+
+const FUNCTION_KEYS: [Key<'static>; 24] = [
+    Key::F1,
+    Key::F2,
+    Key::F3,
+    Key::F4,
+    Key::F5,
+    Key::F6,
+    Key::F7,
+    Key::F8,
+    Key::F9,
+    Key::F10,
+    Key::F11,
+    Key::F12,
+    Key::F13,
+    Key::F14,
+    Key::F15,
+    Key::F16,
+    Key::F17,
+    Key::F18,
+    Key::F19,
+    Key::F20,
+    Key::F21,
+    Key::F22,
+    Key::F23,
+    Key::F24,
+];
+
+const MODIFIERS: &[(KeyModifiers, Modifiers)] = &[
+    (KeyModifiers::SHIFT, Modifiers::SHIFT),
+    (KeyModifiers::CONTROL, Modifiers::CONTROL),
+    (KeyModifiers::ALT, Modifiers::ALT),
+    (KeyModifiers::SUPER, Modifiers::SUPER),
+    (KeyModifiers::HYPER, Modifiers::HYPER),
+    (KeyModifiers::META, Modifiers::META),
+];
+
+const MEDIA_KEYS: &[(MediaKeyCode, Key<'static>)] = &[
+    (MediaKeyCode::Play, Key::MediaPlay),
+    (MediaKeyCode::Pause, Key::MediaPause),
+    (MediaKeyCode::PlayPause, Key::MediaPlayPause),
+    (MediaKeyCode::Stop, Key::MediaStop),
+    (MediaKeyCode::FastForward, Key::MediaFastForward),
+    (MediaKeyCode::Rewind, Key::MediaRewind),
+    (MediaKeyCode::TrackNext, Key::MediaTrackNext),
+    (MediaKeyCode::TrackPrevious, Key::MediaTrackPrevious),
+    (MediaKeyCode::Record, Key::MediaRecord),
+    (MediaKeyCode::LowerVolume, Key::AudioVolumeDown),
+    (MediaKeyCode::RaiseVolume, Key::AudioVolumeUp),
+    (MediaKeyCode::MuteVolume, Key::AudioVolumeMute),
+];
+
+#[rustfmt::skip]
+const MODIFIER_KEYS: &[(ModifierKeyCode, Key<'static>, Code, Location)] = &[
+    (ModifierKeyCode::LeftShift, Key::Shift, Code::ShiftLeft, Location::Left),
+    (ModifierKeyCode::LeftControl, Key::Control, Code::ControlLeft, Location::Left),
+    (ModifierKeyCode::LeftAlt, Key::Alt, Code::AltLeft, Location::Left),
+    (ModifierKeyCode::LeftSuper, Key::Super, Code::MetaLeft, Location::Left),
+    (ModifierKeyCode::LeftHyper, Key::Hyper, Code::Unidentified, Location::Left),
+    (ModifierKeyCode::LeftMeta, Key::Meta, Code::MetaLeft, Location::Left),
+    (ModifierKeyCode::RightShift, Key::Shift, Code::ShiftRight, Location::Right),
+    (ModifierKeyCode::RightControl, Key::Control, Code::ControlRight, Location::Right),
+    (ModifierKeyCode::RightAlt, Key::Alt, Code::AltRight, Location::Right),
+    (ModifierKeyCode::RightSuper, Key::Super, Code::MetaRight, Location::Right),
+    (ModifierKeyCode::RightHyper, Key::Hyper, Code::Unidentified, Location::Right),
+    (ModifierKeyCode::RightMeta, Key::Meta, Code::MetaRight, Location::Right),
+    (ModifierKeyCode::IsoLevel3Shift, Key::AltGraph, Code::Unidentified, Location::Standard),
+];
+
+/// Error returned when a crossterm event can't be converted to a `'static` event.
+///
+/// Only printable ASCII characters are available for `'static`,
+/// use [`KeyboardEvent::from_crossterm`] for all other characters.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct NonAsciiCharError(pub char);
+
+impl fmt::Display for NonAsciiCharError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Character {:?} is not printable ASCII", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for NonAsciiCharError {}
+
+/// Convert a crossterm key event with an ASCII or named key.
+///
+/// Fails for characters other than printable ASCII, which can't be
+/// borrowed for `'static`. [`KeyboardEvent::from_crossterm`] converts
+/// every event.
+impl TryFrom<KeyEvent> for KeyboardEvent<'static> {
+    type Error = NonAsciiCharError;
+
+    fn try_from(event: KeyEvent) -> Result<KeyboardEvent<'static>, NonAsciiCharError> {
+        let key = match event.code {
+            KeyCode::Char(c) => match PRINTABLE.find(c) {
+                Some(i) => Key::Character(&PRINTABLE[i..i + 1]),
+                None => return Err(NonAsciiCharError(c)),
+            },
+            code => named_key(code),
+        };
+        Ok(event_with_key(&event, key))
+    }
+}
+
+impl<'a> KeyboardEvent<'a> {
+    /// Convert a crossterm key event.
+    ///
+    /// This is the conversion to use for terminal input. A character is
+    /// encoded into the provided buffer, like [`Key::from_char`] does.
+    ///
+    /// crossterm reports key presses and repeats as keydown events.
+    /// Releases are only reported if the terminal supports the kitty
+    /// keyboard protocol and become keyup events. The physical key is
+    /// only known for modifier keys and the location for modifier keys
+    /// and the numpad.
+    ///
+    /// ```rust
+    /// # use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    /// # use gluten_keyboard::{Key, KeyboardEvent, KeyState, Modifiers};
+    /// let mut buf = [0; 4];
+    /// let event = KeyEvent::new(KeyCode::Char('é'), KeyModifiers::ALT);
+    /// let event = KeyboardEvent::from_crossterm(event, &mut buf);
+    /// assert_eq!(event.key, Key::Character("é"));
+    /// assert_eq!(event.modifiers, Modifiers::ALT);
+    /// assert_eq!(event.state, KeyState::Down);
+    /// ```
+    pub fn from_crossterm(event: KeyEvent, buf: &'a mut [u8; 4]) -> KeyboardEvent<'a> {
+        let key = match event.code {
+            KeyCode::Char(c) => Key::from_char(c, buf),
+            code => named_key(code),
+        };
+        event_with_key(&event, key)
+    }
+
+    /// Convert the event to a crossterm key event.
+    ///
+    /// Returns `None` if crossterm has no key code for the key,
+    /// including characters longer than a single `char`.
+    /// Keyup events become releases and repeated keydown events
+    /// become repeats.
+    ///
+    /// ```rust
+    /// # use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    /// # use gluten_keyboard::{Key, KeyboardEvent, Modifiers};
+    /// let event = KeyboardEvent {
+    ///     key: Key::F5,
+    ///     modifiers: Modifiers::CONTROL,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     event.to_crossterm(),
+    ///     Some(KeyEvent::new(KeyCode::F(5), KeyModifiers::CONTROL))
+    /// );
+    /// ```
+    pub fn to_crossterm(&self) -> Option<KeyEvent> {
+        let code = match self.key {
+            Key::Character(s) => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => return None,
+                }
+            }
+            Key::Backspace => KeyCode::Backspace,
+            Key::Enter => KeyCode::Enter,
+            Key::ArrowLeft => KeyCode::Left,
+            Key::ArrowRight => KeyCode::Right,
+            Key::ArrowUp => KeyCode::Up,
+            Key::ArrowDown => KeyCode::Down,
+            Key::Home => KeyCode::Home,
+            Key::End => KeyCode::End,
+            Key::PageUp => KeyCode::PageUp,
+            Key::PageDown => KeyCode::PageDown,
+            Key::Tab if self.modifiers.contains(Modifiers::SHIFT) => KeyCode::BackTab,
+            Key::Tab => KeyCode::Tab,
+            Key::Delete => KeyCode::Delete,
+            Key::Insert => KeyCode::Insert,
+            Key::Escape => KeyCode::Esc,
+            Key::CapsLock => KeyCode::CapsLock,
+            Key::ScrollLock => KeyCode::ScrollLock,
+            Key::NumLock => KeyCode::NumLock,
+            Key::PrintScreen => KeyCode::PrintScreen,
+            Key::Pause => KeyCode::Pause,
+            Key::ContextMenu => KeyCode::Menu,
+            Key::Clear if self.location == Location::Numpad => KeyCode::KeypadBegin,
+            key => {
+                if let Some(n) = FUNCTION_KEYS.iter().position(|k| *k == key) {
+                    KeyCode::F(n as u8 + 1)
+                } else if let Some((media, _)) = MEDIA_KEYS.iter().find(|(_, k)| *k == key) {
+                    KeyCode::Media(*media)
+                } else {
+                    let (modifier, _, _, _) = MODIFIER_KEYS
+                        .iter()
+                        .find(|(_, k, _, location)| *k == key && *location == self.location)?;
+                    KeyCode::Modifier(*modifier)
+                }
+            }
+        };
+        let mut modifiers = KeyModifiers::empty();
+        for (flag, modifier) in MODIFIERS {
+            modifiers.set(*flag, self.modifiers.contains(*modifier));
+        }
+        let kind = match (self.state, self.repeat) {
+            (KeyState::Down, false) => KeyEventKind::Press,
+            (KeyState::Down, true) => KeyEventKind::Repeat,
+            (KeyState::Up, _) => KeyEventKind::Release,
+        };
+        let mut state = KeyEventState::empty();
+        state.set(KeyEventState::KEYPAD, self.location == Location::Numpad);
+        state.set(
+            KeyEventState::CAPS_LOCK,
+            self.modifiers.contains(Modifiers::CAPS_LOCK),
+        );
+        state.set(
+            KeyEventState::NUM_LOCK,
+            self.modifiers.contains(Modifiers::NUM_LOCK),
+        );
+        Some(KeyEvent::new_with_kind_and_state(
+            code, modifiers, kind, state,
+        ))
+    }
+}
+
+/// Map all key codes except characters.
+fn named_key(code: KeyCode) -> Key<'static> {
+    match code {
+        KeyCode::Backspace => Key::Backspace,
+        KeyCode::Enter => Key::Enter,
+        KeyCode::Left => Key::ArrowLeft,
+        KeyCode::Right => Key::ArrowRight,
+        KeyCode::Up => Key::ArrowUp,
+        KeyCode::Down => Key::ArrowDown,
+        KeyCode::Home => Key::Home,
+        KeyCode::End => Key::End,
+        KeyCode::PageUp => Key::PageUp,
+        KeyCode::PageDown => Key::PageDown,
+        KeyCode::Tab | KeyCode::BackTab => Key::Tab,
+        KeyCode::Delete => Key::Delete,
+        KeyCode::Insert => Key::Insert,
+        KeyCode::F(n) => FUNCTION_KEYS
+            .get(usize::from(n).wrapping_sub(1))
+            .copied()
+            .unwrap_or(Key::Unidentified(None)),
+        KeyCode::Esc => Key::Escape,
+        KeyCode::CapsLock => Key::CapsLock,
+        KeyCode::ScrollLock => Key::ScrollLock,
+        KeyCode::NumLock => Key::NumLock,
+        KeyCode::PrintScreen => Key::PrintScreen,
+        KeyCode::Pause => Key::Pause,
+        KeyCode::Menu => Key::ContextMenu,
+        KeyCode::KeypadBegin => Key::Clear,
+        KeyCode::Media(media) => MEDIA_KEYS
+            .iter()
+            .find(|(m, _)| *m == media)
+            .map_or(Key::Unidentified(None), |(_, key)| *key),
+        KeyCode::Modifier(modifier) => MODIFIER_KEYS
+            .iter()
+            .find(|(m, _, _, _)| *m == modifier)
+            .map_or(Key::Unidentified(None), |(_, key, _, _)| *key),
+        KeyCode::Char(_) | KeyCode::Null => Key::Unidentified(None),
+    }
+}
+
+fn event_with_key<'a>(event: &KeyEvent, key: Key<'a>) -> KeyboardEvent<'a> {
+    let mut modifiers = Modifiers::empty();
+    for (flag, modifier) in MODIFIERS {
+        modifiers.set(*modifier, event.modifiers.contains(*flag));
+    }
+    if event.code == KeyCode::BackTab {
+        modifiers |= Modifiers::SHIFT;
+    }
+    modifiers.set(
+        Modifiers::CAPS_LOCK,
+        event.state.contains(KeyEventState::CAPS_LOCK),
+    );
+    modifiers.set(
+        Modifiers::NUM_LOCK,
+        event.state.contains(KeyEventState::NUM_LOCK),
+    );
+    let (code, location) = match event.code {
+        KeyCode::Modifier(modifier) => MODIFIER_KEYS
+            .iter()
+            .find(|(m, _, _, _)| *m == modifier)
+            .map_or(
+                (Code::Unidentified, Location::Standard),
+                |(_, _, code, location)| (*code, *location),
+            ),
+        _ if event.state.contains(KeyEventState::KEYPAD) => (Code::Unidentified, Location::Numpad),
+        _ => (Code::Unidentified, Location::Standard),
+    };
+    KeyboardEvent {
+        state: match event.kind {
+            KeyEventKind::Press | KeyEventKind::Repeat => KeyState::Down,
+            KeyEventKind::Release => KeyState::Up,
+        },
+        key,
+        code,
+        location,
+        modifiers,
+        repeat: event.kind == KeyEventKind::Repeat,
+        is_composing: false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_crossterm() {
+        let cases = [
+            (KeyCode::Char('a'), Key::Character("a")),
+            (KeyCode::Char('~'), Key::Character("~")),
+            (KeyCode::F(1), Key::F1),
+            (KeyCode::F(24), Key::F24),
+            (KeyCode::F(25), Key::Unidentified(None)),
+            (KeyCode::Enter, Key::Enter),
+            (KeyCode::Backspace, Key::Backspace),
+            (KeyCode::Left, Key::ArrowLeft),
+            (KeyCode::Right, Key::ArrowRight),
+            (KeyCode::Up, Key::ArrowUp),
+            (KeyCode::Down, Key::ArrowDown),
+            (
+                KeyCode::Media(MediaKeyCode::Reverse),
+                Key::Unidentified(None),
+            ),
+        ];
+        for (code, key) in cases.iter() {
+            let event = KeyboardEvent::try_from(KeyEvent::new(*code, KeyModifiers::NONE)).unwrap();
+            assert_eq!(event.key, *key);
+            assert_eq!(event.state, KeyState::Down);
+            assert_eq!(event.modifiers, Modifiers::empty());
+        }
+        let mut buf = [0; 4];
+        let event = KeyEvent::new(KeyCode::Char('ß'), KeyModifiers::NONE);
+        assert_eq!(KeyboardEvent::try_from(event), Err(NonAsciiCharError('ß')));
+        let event = KeyboardEvent::from_crossterm(event, &mut buf);
+        assert_eq!(event.key, Key::Character("ß"));
+
+        let event = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::all());
+        let event = KeyboardEvent::try_from(event).unwrap();
+        assert_eq!(
+            event.modifiers,
+            Modifiers::SHIFT
+                | Modifiers::CONTROL
+                | Modifiers::ALT
+                | Modifiers::SUPER
+                | Modifiers::HYPER
+                | Modifiers::META
+        );
+        let event = KeyEvent::new(KeyCode::BackTab, KeyModifiers::NONE);
+        let event = KeyboardEvent::try_from(event).unwrap();
+        assert_eq!((event.key, event.modifiers), (Key::Tab, Modifiers::SHIFT));
+
+        let event = KeyboardEvent::try_from(KeyEvent::new_with_kind_and_state(
+            KeyCode::Modifier(ModifierKeyCode::RightShift),
+            KeyModifiers::SHIFT,
+            KeyEventKind::Repeat,
+            KeyEventState::NUM_LOCK,
+        ))
+        .unwrap();
+        assert_eq!(event.key, Key::Shift);
+        assert_eq!(event.code, Code::ShiftRight);
+        assert_eq!(event.location, Location::Right);
+        assert_eq!(event.modifiers, Modifiers::SHIFT | Modifiers::NUM_LOCK);
+        assert_eq!((event.state, event.repeat), (KeyState::Down, true));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let codes = [
+            KeyCode::Char('a'),
+            KeyCode::Char('ß'),
+            KeyCode::F(12),
+            KeyCode::Enter,
+            KeyCode::Backspace,
+            KeyCode::Left,
+            KeyCode::Right,
+            KeyCode::Up,
+            KeyCode::Down,
+            KeyCode::Tab,
+            KeyCode::Menu,
+            KeyCode::Media(MediaKeyCode::PlayPause),
+            KeyCode::Modifier(ModifierKeyCode::LeftSuper),
+            KeyCode::Modifier(ModifierKeyCode::RightHyper),
+        ];
+        let kinds = [
+            KeyEventKind::Press,
+            KeyEventKind::Repeat,
+            KeyEventKind::Release,
+        ];
+        for code in codes.iter() {
+            for kind in kinds.iter() {
+                let event = KeyEvent::new_with_kind_and_state(
+                    *code,
+                    KeyModifiers::CONTROL | KeyModifiers::META,
+                    *kind,
+                    KeyEventState::CAPS_LOCK,
+                );
+                let mut buf = [0; 4];
+                let converted = KeyboardEvent::from_crossterm(event, &mut buf);
+                assert_eq!(converted.to_crossterm(), Some(event));
+            }
+        }
+        let event = KeyboardEvent {
+            key: Key::Character("ab"),
+            ..Default::default()
+        };
+        assert_eq!(event.to_crossterm(), None);
+        let event = KeyboardEvent {
+            key: Key::BrowserBack,
+            ..Default::default()
+        };
+        assert_eq!(event.to_crossterm(), None);
+    }
+}
@@ -0,0 +1,131 @@
+//! Serialize [`KeyState`](crate::KeyState) and
+//! [`CompositionState`](crate::CompositionState) as DOM event types.
+//!
+//! By default the states are serialized by their variant name like `"Down"`.
+//! Use this module with `#[serde(with = "...")]` to use the `type`
+//! strings of the corresponding DOM events instead: `"keydown"`, `"keyup"`,
+//! `"compositionstart"`, `"compositionupdate"` and `"compositionend"`.
+//! This allows to deserialize captured DOM events directly.
+//!
+//! ```rust
+//! # use gluten_keyboard::{CompositionState, KeyState};
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct CapturedKeyEvent {
+//!     #[serde(rename = "type", with = "gluten_keyboard::event_type")]
+//!     state: KeyState,
+//! }
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct CapturedCompositionEvent {
+//!     #[serde(rename = "type", with = "gluten_keyboard::event_type")]
+//!     state: CompositionState,
+//! }
+//! ```
+
+use core::fmt::{self, Display};
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use serde::de::{self, Deserializer, Unexpected, Visitor};
+use serde::ser::Serializer;
+
+/// Serialize the state as a DOM event type.
+pub fn serialize<T, S>(state: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Display,
+    S: Serializer,
+{
+    serializer.collect_str(state)
+}
+
+/// Deserialize the state from a DOM event type.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(EventTypeVisitor(PhantomData))
+}
+
+struct EventTypeVisitor<T>(PhantomData<T>);
+
+impl<'de, T: FromStr> Visitor<'de> for EventTypeVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a DOM event type")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<T, E>
+    where
+        E: de::Error,
+    {
+        v.parse()
+            .map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::fmt;
+    use core::str::FromStr;
+
+    use serde::de::value::{Error, StrDeserializer};
+    use serde::de::IntoDeserializer;
+    use serde::{Deserialize, Serialize};
+
+    use crate::{CompositionState, KeyState};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Event<T: fmt::Display + FromStr> {
+        #[serde(rename = "type", with = "crate::event_type")]
+        state: T,
+    }
+
+    fn round_trip<T>(state: T, json: &str)
+    where
+        T: fmt::Debug + fmt::Display + FromStr + PartialEq,
+    {
+        let event = Event { state };
+        assert_eq!(serde_json::to_string(&event).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Event<T>>(json).unwrap(), event);
+    }
+
+    fn de(s: &str) -> StrDeserializer<'_, Error> {
+        s.into_deserializer()
+    }
+
+    #[test]
+    fn test_deserialize() {
+        assert_eq!(
+            super::deserialize::<KeyState, _>(de("keydown")),
+            Ok(KeyState::Down)
+        );
+        assert_eq!(
+            super::deserialize::<KeyState, _>(de("keyup")),
+            Ok(KeyState::Up)
+        );
+        assert_eq!(
+            super::deserialize::<CompositionState, _>(de("compositionstart")),
+            Ok(CompositionState::Start)
+        );
+        assert_eq!(
+            super::deserialize::<CompositionState, _>(de("compositionupdate")),
+            Ok(CompositionState::Update)
+        );
+        assert_eq!(
+            super::deserialize::<CompositionState, _>(de("compositionend")),
+            Ok(CompositionState::End)
+        );
+        assert!(super::deserialize::<KeyState, _>(de("Down")).is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        round_trip(KeyState::Down, r#"{"type":"keydown"}"#);
+        round_trip(KeyState::Up, r#"{"type":"keyup"}"#);
+        round_trip(CompositionState::Start, r#"{"type":"compositionstart"}"#);
+        round_trip(CompositionState::Update, r#"{"type":"compositionupdate"}"#);
+        round_trip(CompositionState::End, r#"{"type":"compositionend"}"#);
+    }
+}
@@ -0,0 +1,160 @@
+//! Serialize [`KeyboardEvent`] in a shape natural to JavaScript.
+//!
+//! The default serde representation uses the variant names of the enums,
+//! so a numpad location becomes `"Numpad"` or even `{ "Numpad": null }`
+//! with some serializers like `serde-wasm-bindgen`. Use this module with
+//! `#[serde(with = "...")]` to serialize the state as DOM event type
+//! (`"keydown"` or `"keyup"`) and the location as the DOM number (0 to 3).
+//! All other fields keep their default representation, so the key is
+//! still tagged with its variant like `{"Character":"a"}` or `"Enter"`.
+//!
+//! ```rust
+//! # use gluten_keyboard::KeyboardEvent;
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Message<'a> {
+//!     #[serde(borrow, with = "gluten_keyboard::js_event")]
+//!     event: KeyboardEvent<'a>,
+//! }
+//! ```
+//!
+//! The [`location`] module can be used on its own for a single field.
+
+use serde::de::Deserializer;
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+use crate::{Code, Key, KeyState, KeyboardEvent, Location, Modifiers};
+
+#[derive(Serialize, Deserialize)]
+#[serde(bound(deserialize = "'de: 'a"))]
+struct JsEvent<'a> {
+    #[serde(with = "crate::event_type")]
+    state: KeyState,
+    key: Key<'a>,
+    code: Code,
+    #[serde(with = "location")]
+    location: Location,
+    modifiers: Modifiers,
+    repeat: bool,
+    is_composing: bool,
+}
+
+/// Serialize the event with JavaScript friendly state and location.
+pub fn serialize<S>(event: &KeyboardEvent, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    JsEvent {
+        state: event.state,
+        key: event.key,
+        code: event.code,
+        location: event.location,
+        modifiers: event.modifiers,
+        repeat: event.repeat,
+        is_composing: event.is_composing,
+    }
+    .serialize(serializer)
+}
+
+/// Deserialize an event with JavaScript friendly state and location.
+pub fn deserialize<'de: 'a, 'a, D>(deserializer: D) -> Result<KeyboardEvent<'a>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let event = JsEvent::deserialize(deserializer)?;
+    Ok(KeyboardEvent {
+        state: event.state,
+        key: event.key,
+        code: event.code,
+        location: event.location,
+        modifiers: event.modifiers,
+        repeat: event.repeat,
+        is_composing: event.is_composing,
+    })
+}
+
+/// Serialize a [`Location`] as its DOM number.
+///
+/// See [`Location::to_dom`] for the values.
+pub mod location {
+    use serde::de::{Deserializer, Error, Unexpected};
+    use serde::ser::Serializer;
+    use serde::Deserialize;
+
+    use crate::Location;
+
+    /// Serialize the location as a number.
+    pub fn serialize<S>(location: &Location, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u32(location.to_dom())
+    }
+
+    /// Deserialize the location from a number.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Location, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = u32::deserialize(deserializer)?;
+        Location::from_dom(value).ok_or_else(|| {
+            D::Error::invalid_value(Unexpected::Unsigned(value.into()), &"a DOM key location")
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::de::value::{Error, U32Deserializer};
+    use serde::de::IntoDeserializer;
+    use serde::{Deserialize, Serialize};
+
+    use crate::{Code, Key, KeyState, KeyboardEvent, Location, Modifiers};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Message<'a> {
+        #[serde(borrow, with = "crate::js_event")]
+        event: KeyboardEvent<'a>,
+    }
+
+    fn de(value: u32) -> U32Deserializer<Error> {
+        value.into_deserializer()
+    }
+
+    #[test]
+    fn test_location() {
+        for location in &[
+            Location::Standard,
+            Location::Left,
+            Location::Right,
+            Location::Numpad,
+        ] {
+            assert_eq!(
+                super::location::deserialize(de(location.to_dom())),
+                Ok(*location)
+            );
+        }
+        assert!(super::location::deserialize(de(4)).is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let message = Message {
+            event: KeyboardEvent {
+                state: KeyState::Down,
+                key: Key::Character("a"),
+                code: Code::KeyA,
+                location: Location::Numpad,
+                modifiers: Modifiers::SHIFT,
+                repeat: true,
+                is_composing: false,
+            },
+        };
+        let json = serde_json::to_string(&message).unwrap();
+        assert_eq!(
+            json,
+            r#"{"event":{"state":"keydown","key":{"Character":"a"},"code":"KeyA","location":3,"modifiers":512,"repeat":true,"is_composing":false}}"#
+        );
+        assert_eq!(serde_json::from_str::<Message>(&json).unwrap(), message);
+    }
+}
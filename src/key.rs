@@ -17,12 +17,21 @@ pub enum Key<'a> {
     /// A key string that corresponds to the character typed by the user,
     /// taking into account the user’s current locale setting, modifier state,
     /// and any system-level keyboard mapping overrides that are in effect.
+    ///
+    /// The string should be a single non-empty grapheme cluster of
+    /// printable characters. Use [`Key::character`] to check this invariant.
+    ///
+    /// The string is borrowed so constructing a key never allocates.
+    /// [`Key::from_char`] stores a single `char` in a caller provided buffer.
     Character(&'a str),
     
     /// This key value is used when an implementation is unable to
     /// identify another key value, due to either hardware,
     /// platform, or software constraints.
-    Unidentified,
+    ///
+    /// The payload optionally holds the raw platform value
+    /// (like a scancode or virtual key code) that could not be mapped.
+    Unidentified(Option<u32>),
     /// The <code class="keycap">Alt</code> (Alternative) key.<br> This key enables the alternate modifier function for interpreting concurrent or subsequent keyboard input.<br> This key value is also used for the Apple <code class="keycap">Option</code> key.
     Alt,
     /// The Alternate Graphics (<code class="keycap">AltGr</code> or <code class="keycap">AltGraph</code>) key.
@@ -651,7 +660,7 @@ impl Display for Key<'_> {
         match *self {
             Character(s) => f.write_str(s),
     
-            Unidentified => f.write_str("Unidentified"),
+            Unidentified(_) => f.write_str("Unidentified"),
             Alt => f.write_str("Alt"),
             AltGraph => f.write_str("AltGraph"),
             CapsLock => f.write_str("CapsLock"),
@@ -958,7 +967,7 @@ impl<'a> Key<'a> {
         use Key::*;
         match s {
             s if is_key_string(s) => Ok(Character(s)),
-            "Unidentified" => Ok(Unidentified),
+            "Unidentified" => Ok(Unidentified(None)),
             "Alt" => Ok(Alt),
             "AltGraph" => Ok(AltGraph),
             "CapsLock" => Ok(CapsLock),
@@ -0,0 +1,347 @@
+//! Keyboard layouts.
+
+use crate::{Code, Key, KeyState, KeyboardEvent, Location, Modifiers};
+
+/// Translate physical key positions to logical key values.
+///
+/// Printable keys can produce up to four different keys depending on
+/// the ISO 9995 shift level. The level numbering follows the XKB convention:
+///
+/// | Level | Modifiers          | Example (German layout, `KeyQ`) |
+/// |-------|--------------------|---------------------------------|
+/// | 1     | none               | `q`                             |
+/// | 2     | Shift              | `Q`                             |
+/// | 3     | AltGraph           | `@`                             |
+/// | 4     | Shift + AltGraph   | `Ω`                             |
+pub trait Layout {
+    /// Get the key produced by a physical key on the given shift level.
+    ///
+    /// The levels are numbered from 1 to 4, see the [trait
+    /// documentation](Layout) for their meaning. Layouts that do not define
+    /// the third and fourth level return the key of the first or
+    /// second level respectively, just like XKB does.
+    ///
+    /// Keys without a character value like `Enter` or `ShiftLeft`
+    /// resolve to the same named key on all levels.
+    /// If the layout does not know the code `Key::Unidentified` is returned.
+    fn resolve_level(&self, code: Code, level: u8) -> Key<'_>;
+
+    /// Get the key produced by a physical key with the modifiers held.
+    ///
+    /// The default implementation selects the level with
+    /// [`Modifiers::level`]. Layouts with further state like
+    /// CapsLock handling should override this method.
+    fn resolve(&self, code: Code, modifiers: Modifiers) -> Key<'_> {
+        self.resolve_level(code, modifiers.level())
+    }
+
+    /// Find a physical key and modifiers producing the key.
+    ///
+    /// This is the reverse of [`resolve`](Self::resolve). If multiple
+    /// keys produce the key, one needing the fewest modifiers is
+    /// returned.
+    ///
+    /// The default implementation finds no keys.
+    fn find_key(&self, target: &Key) -> Option<(Code, Modifiers)> {
+        let _ = target;
+        None
+    }
+}
+
+impl<'a> KeyboardEvent<'a> {
+    /// Create an event for a physical key going down or up.
+    ///
+    /// The key is resolved with the layout using the modifiers, so it is
+    /// only as accurate as the supplied layout. The location is derived
+    /// with [`Location::from_code`]. The modifiers are used as given
+    /// and the event is neither repeated nor composing.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::{Code, Key, KeyboardEvent, KeyState, Modifiers, UsQwerty};
+    /// let event = KeyboardEvent::from_physical(KeyState::Down, Code::KeyA, Modifiers::SHIFT, &UsQwerty);
+    /// assert_eq!(event.key, Key::Character("A"));
+    /// ```
+    pub fn from_physical(
+        state: KeyState,
+        code: Code,
+        modifiers: Modifiers,
+        layout: &'a dyn Layout,
+    ) -> KeyboardEvent<'a> {
+        KeyboardEvent {
+            state,
+            key: layout.resolve(code, modifiers),
+            code,
+            location: Location::from_code(code),
+            modifiers,
+            repeat: false,
+            is_composing: false,
+        }
+    }
+}
+
+/// The US QWERTY layout.
+///
+/// The layout only has two levels, AltGraph is ignored.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct UsQwerty;
+
+impl Layout for UsQwerty {
+    fn resolve_level(&self, code: Code, level: u8) -> Key<'_> {
+        if let Some(key) = named_key(code) {
+            return key;
+        }
+        match US_QWERTY.iter().find(|(c, _, _)| *c == code) {
+            Some((_, _, shifted)) if level % 2 == 0 => Key::Character(shifted),
+            Some((_, base, _)) => Key::Character(base),
+            None => Key::Unidentified(None),
+        }
+    }
+
+    /// Find the key producing a character.
+    ///
+    /// Named keys like `Enter` are not found. Keys producing the character
+    /// without modifiers are preferred, so `+` is found on the numpad.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::{Code, Key, Layout, Modifiers, UsQwerty};
+    /// assert_eq!(
+    ///     UsQwerty.find_key(&Key::Character("@")),
+    ///     Some((Code::Digit2, Modifiers::SHIFT))
+    /// );
+    /// assert_eq!(
+    ///     UsQwerty.find_key(&Key::Character("+")),
+    ///     Some((Code::NumpadAdd, Modifiers::empty()))
+    /// );
+    /// ```
+    fn find_key(&self, target: &Key) -> Option<(Code, Modifiers)> {
+        let target = match target {
+            Key::Character(s) => *s,
+            _ => return None,
+        };
+        let base = US_QWERTY.iter().find(|(_, base, _)| *base == target);
+        if let Some((code, _, _)) = base {
+            return Some((*code, Modifiers::empty()));
+        }
+        let shifted = US_QWERTY.iter().find(|(_, _, shifted)| *shifted == target);
+        shifted.map(|(code, _, _)| (*code, Modifiers::SHIFT))
+    }
+}
+
+/// Printable keys of the US QWERTY layout on level 1 and 2.
+const US_QWERTY: &[(Code, &str, &str)] = &[
+    (Code::Backquote, "`", "~"),
+    (Code::Digit1, "1", "!"),
+    (Code::Digit2, "2", "@"),
+    (Code::Digit3, "3", "#"),
+    (Code::Digit4, "4", "$"),
+    (Code::Digit5, "5", "%"),
+    (Code::Digit6, "6", "^"),
+    (Code::Digit7, "7", "&"),
+    (Code::Digit8, "8", "*"),
+    (Code::Digit9, "9", "("),
+    (Code::Digit0, "0", ")"),
+    (Code::Minus, "-", "_"),
+    (Code::Equal, "=", "+"),
+    (Code::KeyQ, "q", "Q"),
+    (Code::KeyW, "w", "W"),
+    (Code::KeyE, "e", "E"),
+    (Code::KeyR, "r", "R"),
+    (Code::KeyT, "t", "T"),
+    (Code::KeyY, "y", "Y"),
+    (Code::KeyU, "u", "U"),
+    (Code::KeyI, "i", "I"),
+    (Code::KeyO, "o", "O"),
+    (Code::KeyP, "p", "P"),
+    (Code::BracketLeft, "[", "{"),
+    (Code::BracketRight, "]", "}"),
+    (Code::Backslash, "\\", "|"),
+    (Code::KeyA, "a", "A"),
+    (Code::KeyS, "s", "S"),
+    (Code::KeyD, "d", "D"),
+    (Code::KeyF, "f", "F"),
+    (Code::KeyG, "g", "G"),
+    (Code::KeyH, "h", "H"),
+    (Code::KeyJ, "j", "J"),
+    (Code::KeyK, "k", "K"),
+    (Code::KeyL, "l", "L"),
+    (Code::Semicolon, ";", ":"),
+    (Code::Quote, "'", "\""),
+    (Code::KeyZ, "z", "Z"),
+    (Code::KeyX, "x", "X"),
+    (Code::KeyC, "c", "C"),
+    (Code::KeyV, "v", "V"),
+    (Code::KeyB, "b", "B"),
+    (Code::KeyN, "n", "N"),
+    (Code::KeyM, "m", "M"),
+    (Code::Comma, ",", "<"),
+    (Code::Period, ".", ">"),
+    (Code::Slash, "/", "?"),
+    (Code::Space, " ", " "),
+    (Code::Numpad0, "0", "0"),
+    (Code::Numpad1, "1", "1"),
+    (Code::Numpad2, "2", "2"),
+    (Code::Numpad3, "3", "3"),
+    (Code::Numpad4, "4", "4"),
+    (Code::Numpad5, "5", "5"),
+    (Code::Numpad6, "6", "6"),
+    (Code::Numpad7, "7", "7"),
+    (Code::Numpad8, "8", "8"),
+    (Code::Numpad9, "9", "9"),
+    (Code::NumpadAdd, "+", "+"),
+    (Code::NumpadComma, ",", ","),
+    (Code::NumpadDecimal, ".", "."),
+    (Code::NumpadDivide, "/", "/"),
+    (Code::NumpadEqual, "=", "="),
+    (Code::NumpadMultiply, "*", "*"),
+    (Code::NumpadSubtract, "-", "-"),
+];
+
+/// Get the layout independent key for codes without a character value.
+fn named_key(code: Code) -> Option<Key<'static>> {
+    let key = match code {
+        Code::AltLeft | Code::AltRight => Key::Alt,
+        Code::Backspace | Code::NumpadBackspace => Key::Backspace,
+        Code::CapsLock => Key::CapsLock,
+        Code::ContextMenu => Key::ContextMenu,
+        Code::ControlLeft | Code::ControlRight => Key::Control,
+        Code::Enter | Code::NumpadEnter => Key::Enter,
+        Code::MetaLeft | Code::MetaRight => Key::Meta,
+        Code::ShiftLeft | Code::ShiftRight => Key::Shift,
+        Code::Tab => Key::Tab,
+        Code::Convert => Key::Convert,
+        Code::KanaMode => Key::KanaMode,
+        Code::Lang1 => Key::HangulMode,
+        Code::Lang2 => Key::HanjaMode,
+        Code::NonConvert => Key::NonConvert,
+        Code::Delete => Key::Delete,
+        Code::End => Key::End,
+        Code::Help => Key::Help,
+        Code::Home => Key::Home,
+        Code::Insert => Key::Insert,
+        Code::PageDown => Key::PageDown,
+        Code::PageUp => Key::PageUp,
+        Code::ArrowDown => Key::ArrowDown,
+        Code::ArrowLeft => Key::ArrowLeft,
+        Code::ArrowRight => Key::ArrowRight,
+        Code::ArrowUp => Key::ArrowUp,
+        Code::NumLock => Key::NumLock,
+        Code::NumpadClear => Key::Clear,
+        Code::Escape => Key::Escape,
+        Code::F1 => Key::F1,
+        Code::F2 => Key::F2,
+        Code::F3 => Key::F3,
+        Code::F4 => Key::F4,
+        Code::F5 => Key::F5,
+        Code::F6 => Key::F6,
+        Code::F7 => Key::F7,
+        Code::F8 => Key::F8,
+        Code::F9 => Key::F9,
+        Code::F10 => Key::F10,
+        Code::F11 => Key::F11,
+        Code::F12 => Key::F12,
+        Code::F13 => Key::F13,
+        Code::F14 => Key::F14,
+        Code::F15 => Key::F15,
+        Code::F16 => Key::F16,
+        Code::F17 => Key::F17,
+        Code::F18 => Key::F18,
+        Code::F19 => Key::F19,
+        Code::F20 => Key::F20,
+        Code::F21 => Key::F21,
+        Code::F22 => Key::F22,
+        Code::F23 => Key::F23,
+        Code::F24 => Key::F24,
+        Code::Fn => Key::Fn,
+        Code::FnLock => Key::FnLock,
+        Code::PrintScreen => Key::PrintScreen,
+        Code::ScrollLock => Key::ScrollLock,
+        Code::Pause => Key::Pause,
+        Code::BrowserBack => Key::BrowserBack,
+        Code::BrowserFavorites => Key::BrowserFavorites,
+        Code::BrowserForward => Key::BrowserForward,
+        Code::BrowserHome => Key::BrowserHome,
+        Code::BrowserRefresh => Key::BrowserRefresh,
+        Code::BrowserSearch => Key::BrowserSearch,
+        Code::BrowserStop => Key::BrowserStop,
+        Code::Eject => Key::Eject,
+        Code::LaunchApp1 => Key::LaunchApplication1,
+        Code::LaunchApp2 => Key::LaunchApplication2,
+        Code::LaunchMail => Key::LaunchMail,
+        Code::MediaPlayPause => Key::MediaPlayPause,
+        Code::MediaSelect => Key::LaunchMediaPlayer,
+        Code::MediaStop => Key::MediaStop,
+        Code::MediaTrackNext => Key::MediaTrackNext,
+        Code::MediaTrackPrevious => Key::MediaTrackPrevious,
+        Code::Power => Key::Power,
+        Code::Sleep => Key::Standby,
+        Code::AudioVolumeDown => Key::AudioVolumeDown,
+        Code::AudioVolumeMute => Key::AudioVolumeMute,
+        Code::AudioVolumeUp => Key::AudioVolumeUp,
+        Code::WakeUp => Key::WakeUp,
+        Code::Hyper => Key::Hyper,
+        Code::Super => Key::Super,
+        Code::Again => Key::Again,
+        Code::Copy => Key::Copy,
+        Code::Cut => Key::Cut,
+        Code::Find => Key::Find,
+        Code::Open => Key::Open,
+        Code::Paste => Key::Paste,
+        Code::Props => Key::Props,
+        Code::Select => Key::Select,
+        Code::Undo => Key::Undo,
+        Code::Hiragana => Key::Hiragana,
+        Code::Katakana => Key::Katakana,
+        Code::BrightnessDown => Key::BrightnessDown,
+        Code::BrightnessUp => Key::BrightnessUp,
+        Code::LaunchScreenSaver => Key::LaunchScreenSaver,
+        Code::MailForward => Key::MailForward,
+        Code::MailReply => Key::MailReply,
+        Code::MailSend => Key::MailSend,
+        Code::MediaFastForward => Key::MediaFastForward,
+        Code::MediaPause => Key::MediaPause,
+        Code::MediaPlay => Key::MediaPlay,
+        Code::MediaRecord => Key::MediaRecord,
+        Code::MediaRewind => Key::MediaRewind,
+        Code::ZoomToggle => Key::ZoomToggle,
+        _ => return None,
+    };
+    Some(key)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_us_qwerty() {
+        assert_eq!(UsQwerty.resolve_level(Code::KeyQ, 1), Key::Character("q"));
+        assert_eq!(UsQwerty.resolve_level(Code::KeyQ, 2), Key::Character("Q"));
+        assert_eq!(UsQwerty.resolve_level(Code::KeyQ, 3), Key::Character("q"));
+        assert_eq!(UsQwerty.resolve_level(Code::KeyQ, 4), Key::Character("Q"));
+        assert_eq!(
+            UsQwerty.resolve(Code::Digit2, Modifiers::SHIFT | Modifiers::CONTROL),
+            Key::Character("@")
+        );
+        assert_eq!(
+            UsQwerty.resolve(Code::ShiftLeft, Modifiers::SHIFT),
+            Key::Shift
+        );
+        assert_eq!(
+            UsQwerty.resolve(Code::IntlRo, Modifiers::empty()),
+            Key::Unidentified(None)
+        );
+        assert_eq!(
+            UsQwerty.find_key(&Key::Character("q")),
+            Some((Code::KeyQ, Modifiers::empty()))
+        );
+        assert_eq!(
+            UsQwerty.find_key(&Key::Character("*")),
+            Some((Code::NumpadMultiply, Modifiers::empty()))
+        );
+        assert_eq!(
+            UsQwerty.find_key(&Key::Character("Q")),
+            Some((Code::KeyQ, Modifiers::SHIFT))
+        );
+        assert_eq!(UsQwerty.find_key(&Key::Character("é")), None);
+    }
+}
@@ -7,21 +7,93 @@
 #![forbid(unsafe_code, future_incompatible)]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 use core::fmt;
+use core::str::FromStr;
+
+#[cfg(feature = "std")]
+use std::error::Error;
 
+pub use category::TransportAction;
+pub use character::KeyError;
 pub use code::{Code, UnrecognizedCodeError};
+#[cfg(feature = "alloc")]
+pub use compiled::CompiledShortcuts;
+#[cfg(feature = "alloc")]
+pub use compose::ComposeTable;
+pub use composition::CompositionSession;
+#[cfg(feature = "crossterm")]
+pub use crossterm_event::NonAsciiCharError;
 pub use key::{Key, UnrecognizedKeyError};
+pub use layout::{Layout, UsQwerty};
 pub use location::Location;
-pub use modifiers::Modifiers;
+pub use modifiers::{DomModifiers, ModifierOrder, Modifiers};
+pub use pattern::{KeyMatch, KeyPattern, ModifierMatch};
+#[cfg(feature = "alloc")]
+pub use replay::{from_replay_string, to_replay_string, ReplayError};
+#[cfg(feature = "alloc")]
+pub use sequence::{SequenceMatcher, SequenceResult};
 pub use shortcuts::ShortcutMatcher;
+#[cfg(feature = "alloc")]
+pub use terminal::TerminalDecoder;
+#[cfg(feature = "alloc")]
+pub use text::extract_text;
+pub use timing::{DoublePressDetector, TimedEvent};
+#[cfg(feature = "alloc")]
+pub use tracker::{coalesce, PressedKeys};
+pub use tracker::{KeyboardState, ModifierTracker};
 
+mod category;
+mod character;
 #[rustfmt::skip]
 mod code;
+#[cfg(feature = "alloc")]
+mod compiled;
+#[cfg(feature = "alloc")]
+mod compose;
+mod composition;
+#[cfg(feature = "crossterm")]
+mod crossterm_event;
+#[cfg(feature = "serde")]
+pub mod event_type;
+#[rustfmt::skip]
+mod grapheme_table;
+#[cfg(feature = "serde")]
+pub mod js_event;
 #[rustfmt::skip]
 mod key;
+mod layout;
 mod location;
 mod modifiers;
+#[cfg(feature = "serde")]
+pub mod modifiers_array;
+mod pattern;
+mod remap;
+#[cfg(feature = "alloc")]
+mod replay;
+mod scancode;
+#[cfg(feature = "alloc")]
+mod sequence;
 mod shortcuts;
+#[cfg(feature = "serde")]
+pub mod skip_defaults;
+mod table;
+mod telemetry;
+#[cfg(feature = "alloc")]
+mod terminal;
+#[cfg(feature = "alloc")]
+mod text;
+mod timing;
+mod tracker;
+#[cfg(feature = "unicode")]
+mod unicode;
+#[cfg(feature = "unicode")]
+#[rustfmt::skip]
+mod unicode_table;
 
 /// Describes the state the key is in.
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
@@ -53,16 +125,20 @@ pub struct KeyboardEvent<'a> {
     pub code: Code,
 
     /// Location for keys with multiple instances on common keyboards.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub location: Location,
 
     /// Flags for pressed modifier keys.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub modifiers: Modifiers,
 
     /// True if the key is currently auto-repeated.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub repeat: bool,
 
     /// Events with this flag should be ignored in a text editor
     /// and instead composition events should be used.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub is_composing: bool,
 }
 
@@ -93,6 +169,20 @@ impl fmt::Display for CompositionState {
     }
 }
 
+impl FromStr for CompositionState {
+    type Err = UnrecognizedStateError;
+
+    /// Parse the DOM event type like `"compositionstart"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "compositionstart" => Ok(CompositionState::Start),
+            "compositionupdate" => Ok(CompositionState::Update),
+            "compositionend" => Ok(CompositionState::End),
+            _ => Err(UnrecognizedStateError),
+        }
+    }
+}
+
 /// Event to expose input methods to program logic.
 ///
 /// Provides information about entered sequences from
@@ -120,6 +210,32 @@ impl fmt::Display for KeyState {
     }
 }
 
+impl FromStr for KeyState {
+    type Err = UnrecognizedStateError;
+
+    /// Parse the DOM event type `"keydown"` or `"keyup"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "keydown" => Ok(KeyState::Down),
+            "keyup" => Ok(KeyState::Up),
+            _ => Err(UnrecognizedStateError),
+        }
+    }
+}
+
+/// Parse from string error, returned when string is not a known DOM event type.
+#[derive(Clone, Debug)]
+pub struct UnrecognizedStateError;
+
+impl fmt::Display for UnrecognizedStateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Unrecognized state")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for UnrecognizedStateError {}
+
 impl Key<'_> {
     /// Determine a *charCode* value for a key with a character value.
     ///
@@ -146,12 +262,22 @@ impl Key<'_> {
             // See: https://w3c.github.io/uievents/#fixed-virtual-key-codes
             Key::Backspace => 8,
             Key::Tab => 9,
+            Key::Clear => 12,
             Key::Enter => 13,
             Key::Shift => 16,
             Key::Control => 17,
             Key::Alt => 18,
             Key::CapsLock => 20,
+            // Windows virtual keys for IMEs, used by all browsers.
+            Key::HangulMode | Key::KanaMode => 21,
+            Key::JunjaMode => 23,
+            Key::FinalMode => 24,
+            Key::HanjaMode | Key::KanjiMode => 25,
             Key::Escape => 27,
+            Key::Convert => 28,
+            Key::NonConvert => 29,
+            Key::Accept => 30,
+            Key::ModeChange => 31,
             Key::PageUp => 33,
             Key::PageDown => 34,
             Key::End => 35,
@@ -160,7 +286,12 @@ impl Key<'_> {
             Key::ArrowUp => 38,
             Key::ArrowRight => 39,
             Key::ArrowDown => 40,
+            Key::Insert => 45,
             Key::Delete => 46,
+            Key::Process => 229,
+            Key::Alphanumeric => 240,
+            Key::Katakana => 241,
+            Key::Hiragana => 242,
             Key::Character(c) if c.len() == 1 => match first_char(c) {
                 ' ' => 32,
                 x @ '0'..='9' => x as u32,
@@ -185,6 +316,77 @@ impl Key<'_> {
     }
 }
 
+/// Legacy properties of a DOM *keypress* event.
+///
+/// Specification: <https://w3c.github.io/uievents/#legacy-key-models>
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct LegacyKeypress {
+    /// The *keyCode* property, equal to the *charCode* for keypress events.
+    pub key_code: u32,
+
+    /// The *charCode* property, the code point of the character.
+    pub char_code: u32,
+
+    /// The *which* property, equal to the *charCode* for keypress events.
+    pub which: u32,
+}
+
+impl KeyboardEvent<'_> {
+    /// Determine the legacy *keypress* event fired for this event.
+    ///
+    /// Browsers fire a keypress event after the keydown of a key
+    /// that produces a character value. All three legacy codes
+    /// are set to the code point of the character.
+    /// The Enter key also fires a keypress with the code 13.
+    ///
+    /// Returns `None` for keyup events, events during a composition
+    /// and keys without a character value.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::{Key, KeyboardEvent};
+    /// let event = KeyboardEvent {
+    ///     key: Key::Character("a"),
+    ///     ..Default::default()
+    /// };
+    /// let keypress = event.as_legacy_keypress().unwrap();
+    /// assert_eq!(keypress.char_code, 97);
+    /// assert_eq!(keypress.key_code, 97);
+    /// ```
+    pub fn as_legacy_keypress(&self) -> Option<LegacyKeypress> {
+        if self.state != KeyState::Down || self.is_composing {
+            return None;
+        }
+        let code = match self.key {
+            Key::Character(_) => self.key.legacy_charcode(),
+            Key::Enter => 13,
+            _ => return None,
+        };
+        if code == 0 {
+            return None;
+        }
+        Some(LegacyKeypress {
+            key_code: code,
+            char_code: code,
+            which: code,
+        })
+    }
+
+    /// Check if two events belong to the same physical key.
+    ///
+    /// Events are compared by their code. If either code is unidentified,
+    /// as some backends don't report it, the key and location are compared
+    /// instead. This fallback is best-effort: the key value of a held key
+    /// can change if the modifiers change, e.g. `a` becomes `A`
+    /// when Shift is pressed.
+    pub fn same_physical_key(&self, other: &KeyboardEvent) -> bool {
+        if self.code != Code::Unidentified && other.code != Code::Unidentified {
+            self.code == other.code
+        } else {
+            self.key == other.key && self.location == other.location
+        }
+    }
+}
+
 impl Default for KeyState {
     fn default() -> KeyState {
         KeyState::Down
@@ -193,7 +395,7 @@ impl Default for KeyState {
 
 impl Default for Key<'_> {
     fn default() -> Self {
-        Key::Unidentified
+        Key::Unidentified(None)
     }
 }
 
@@ -216,3 +418,101 @@ impl Default for Location {
 fn first_char(s: &str) -> char {
     s.chars().next().expect("empty string")
 }
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use std::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_state_round_trip() {
+        for state in &[KeyState::Down, KeyState::Up] {
+            assert_eq!(state.to_string().parse::<KeyState>().unwrap(), *state);
+        }
+        for state in &[
+            CompositionState::Start,
+            CompositionState::Update,
+            CompositionState::End,
+        ] {
+            assert_eq!(
+                state.to_string().parse::<CompositionState>().unwrap(),
+                *state
+            );
+        }
+        assert!("KeyDown".parse::<KeyState>().is_err());
+    }
+
+    #[test]
+    fn test_editing_keycodes() {
+        assert_eq!(Key::Clear.legacy_keycode(), 12);
+        assert_eq!(Key::Insert.legacy_keycode(), 45);
+        assert_eq!(Key::Paste.legacy_keycode(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_unidentified_serde() {
+        let key = Key::Unidentified(Some(29));
+        let json = serde_json::to_string(&key).unwrap();
+        assert_eq!(json, r#"{"Unidentified":29}"#);
+        assert_eq!(serde_json::from_str::<Key>(&json).unwrap(), key);
+        let json = serde_json::to_string(&Key::Unidentified(None)).unwrap();
+        assert_eq!(json, r#"{"Unidentified":null}"#);
+        assert_eq!(
+            serde_json::from_str::<Key>(&json).unwrap(),
+            Key::Unidentified(None)
+        );
+        assert_eq!(Key::Unidentified(Some(65)).legacy_keycode(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        let events = [
+            KeyboardEvent::default(),
+            KeyboardEvent {
+                state: KeyState::Up,
+                key: Key::Character("a"),
+                code: Code::KeyA,
+                location: Location::Left,
+                modifiers: Modifiers::CONTROL,
+                repeat: true,
+                is_composing: true,
+            },
+        ];
+        for event in events.iter() {
+            let bytes = bincode::serialize(event).unwrap();
+            assert_eq!(
+                bincode::deserialize::<KeyboardEvent>(&bytes).unwrap(),
+                *event
+            );
+            let json = serde_json::to_string(event).unwrap();
+            assert_eq!(
+                serde_json::from_str::<KeyboardEvent>(&json).unwrap(),
+                *event
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_deserialize_defaults() {
+        use serde::de::value::{Error, MapDeserializer};
+        use serde::Deserialize;
+
+        let fields = [("state", "Down"), ("key", "Enter"), ("code", "NumpadEnter")];
+        let deserializer = MapDeserializer::<_, Error>::new(fields.iter().copied());
+        let event = KeyboardEvent::deserialize(deserializer).unwrap();
+        assert_eq!(
+            event,
+            KeyboardEvent {
+                key: Key::Enter,
+                code: Code::NumpadEnter,
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -1,5 +1,12 @@
+use crate::Code;
+
 /// The location attribute contains an indication of the logical location
 /// of the key on the device.
+///
+/// The variants match the `DOM_KEY_LOCATION_*` constants of the
+/// UI Events specification.
+///
+/// Specification: <https://w3c.github.io/uievents/#dom-keyboardevent-location>
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Location {
@@ -23,3 +30,101 @@ pub enum Location {
     /// always be encoded with a location of DOM_KEY_LOCATION_STANDARD.
     Numpad = 0x03,
 }
+
+impl Location {
+    /// Numeric value of `Location::Standard` in the DOM.
+    pub const DOM_KEY_LOCATION_STANDARD: u32 = 0x00;
+
+    /// Numeric value of `Location::Left` in the DOM.
+    pub const DOM_KEY_LOCATION_LEFT: u32 = 0x01;
+
+    /// Numeric value of `Location::Right` in the DOM.
+    pub const DOM_KEY_LOCATION_RIGHT: u32 = 0x02;
+
+    /// Numeric value of `Location::Numpad` in the DOM.
+    pub const DOM_KEY_LOCATION_NUMPAD: u32 = 0x03;
+
+    /// Get the numeric location value used by the DOM.
+    pub fn to_dom(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Create a location from the numeric DOM value.
+    ///
+    /// Returns `None` for values not defined by the specification.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::Location;
+    /// assert_eq!(Location::from_dom(2), Some(Location::Right));
+    /// assert_eq!(Location::from_dom(4), None);
+    /// ```
+    pub fn from_dom(value: u32) -> Option<Location> {
+        match value {
+            Location::DOM_KEY_LOCATION_STANDARD => Some(Location::Standard),
+            Location::DOM_KEY_LOCATION_LEFT => Some(Location::Left),
+            Location::DOM_KEY_LOCATION_RIGHT => Some(Location::Right),
+            Location::DOM_KEY_LOCATION_NUMPAD => Some(Location::Numpad),
+            _ => None,
+        }
+    }
+
+    /// Check if the location is `Location::Standard`.
+    pub fn is_standard(&self) -> bool {
+        *self == Location::Standard
+    }
+
+    /// Check if the key is the left or right instance of a key.
+    pub fn is_side(&self) -> bool {
+        matches!(self, Location::Left | Location::Right)
+    }
+
+    /// Check if the key is on the numeric keypad.
+    pub fn is_numpad(&self) -> bool {
+        *self == Location::Numpad
+    }
+
+    /// Derive the location from the physical key.
+    ///
+    /// The left and right modifier keys have the `Left` and
+    /// `Right` location. Keys on the numeric keypad have the `Numpad`
+    /// location, except for NumLock. All other keys are `Standard`.
+    pub fn from_code(code: Code) -> Location {
+        match code {
+            Code::AltLeft | Code::ControlLeft | Code::MetaLeft | Code::ShiftLeft => Location::Left,
+            Code::AltRight | Code::ControlRight | Code::MetaRight | Code::ShiftRight => {
+                Location::Right
+            }
+            Code::Numpad0
+            | Code::Numpad1
+            | Code::Numpad2
+            | Code::Numpad3
+            | Code::Numpad4
+            | Code::Numpad5
+            | Code::Numpad6
+            | Code::Numpad7
+            | Code::Numpad8
+            | Code::Numpad9
+            | Code::NumpadAdd
+            | Code::NumpadBackspace
+            | Code::NumpadClear
+            | Code::NumpadClearEntry
+            | Code::NumpadComma
+            | Code::NumpadDecimal
+            | Code::NumpadDivide
+            | Code::NumpadEnter
+            | Code::NumpadEqual
+            | Code::NumpadHash
+            | Code::NumpadMemoryAdd
+            | Code::NumpadMemoryClear
+            | Code::NumpadMemoryRecall
+            | Code::NumpadMemoryStore
+            | Code::NumpadMemorySubtract
+            | Code::NumpadMultiply
+            | Code::NumpadParenLeft
+            | Code::NumpadParenRight
+            | Code::NumpadStar
+            | Code::NumpadSubtract => Location::Numpad,
+            _ => Location::Standard,
+        }
+    }
+}
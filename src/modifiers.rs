@@ -5,9 +5,29 @@
 //!
 //! Use the constants to match for combinations of the modifier keys.
 
+use core::fmt;
+
+use crate::{Key, KeyboardEvent};
+
 bitflags::bitflags! {
     /// Pressed modifier keys.
     ///
+    /// The `FN` and `SYMBOL` flags and their locks belong to the Fn key
+    /// found on laptops and the symbol layer of some mobile keyboards.
+    /// Most platforms handle the Fn key in hardware or firmware and never
+    /// report it, so these flags are only set where the platform exposes them.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::{Key, KeyboardEvent, Modifiers};
+    /// assert_eq!(Key::Fn.modifier_flag(), Some(Modifiers::FN));
+    /// let event = KeyboardEvent {
+    ///     key: Key::F5,
+    ///     modifiers: Modifiers::FN,
+    ///     ..Default::default()
+    /// };
+    /// assert!(event.modifier_state("Fn"));
+    /// ```
+    ///
     /// Specification:
     /// <https://w3c.github.io/uievents-key/#keys-modifier>
     #[derive(Default)]
@@ -30,3 +50,400 @@ bitflags::bitflags! {
         const SUPER = 0x2000;
     }
 }
+
+/// Names of the modifier flags as used by the UI Events specification.
+///
+/// The names are the `Key` values of the corresponding modifier keys
+/// and are listed in bit order.
+pub(crate) const MODIFIER_NAMES: &[(Modifiers, &str)] = &[
+    (Modifiers::ALT, "Alt"),
+    (Modifiers::ALT_GRAPH, "AltGraph"),
+    (Modifiers::CAPS_LOCK, "CapsLock"),
+    (Modifiers::CONTROL, "Control"),
+    (Modifiers::FN, "Fn"),
+    (Modifiers::FN_LOCK, "FnLock"),
+    (Modifiers::META, "Meta"),
+    (Modifiers::NUM_LOCK, "NumLock"),
+    (Modifiers::SCROLL_LOCK, "ScrollLock"),
+    (Modifiers::SHIFT, "Shift"),
+    (Modifiers::SYMBOL, "Symbol"),
+    (Modifiers::SYMBOL_LOCK, "SymbolLock"),
+    (Modifiers::HYPER, "Hyper"),
+    (Modifiers::SUPER, "Super"),
+];
+
+/// Order in which modifier names are written.
+///
+/// Modifiers not listed in the order are written after the listed ones
+/// in the order of their bits.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ModifierOrder<'a> {
+    order: &'a [Modifiers],
+}
+
+impl ModifierOrder<'static> {
+    /// The conventional order Control, Alt, Shift, Meta.
+    ///
+    /// These are followed by AltGraph, Fn, Symbol, Hyper, Super
+    /// and finally the lock states.
+    pub const DEFAULT: ModifierOrder<'static> = ModifierOrder {
+        order: &[
+            Modifiers::CONTROL,
+            Modifiers::ALT,
+            Modifiers::SHIFT,
+            Modifiers::META,
+            Modifiers::ALT_GRAPH,
+            Modifiers::FN,
+            Modifiers::SYMBOL,
+            Modifiers::HYPER,
+            Modifiers::SUPER,
+        ],
+    };
+}
+
+impl<'a> ModifierOrder<'a> {
+    /// Create an order from a list of modifier flags.
+    ///
+    /// An item with several flags writes them in the order of their bits,
+    /// empty items are skipped.
+    pub const fn new(order: &'a [Modifiers]) -> ModifierOrder<'a> {
+        ModifierOrder { order }
+    }
+}
+
+impl Default for ModifierOrder<'static> {
+    fn default() -> Self {
+        ModifierOrder::DEFAULT
+    }
+}
+
+impl Modifiers {
+    /// Format the modifiers in the given order.
+    ///
+    /// The names are joined with `+`.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::{ModifierOrder, Modifiers};
+    /// let modifiers = Modifiers::CONTROL | Modifiers::SHIFT;
+    /// let shift_first = ModifierOrder::new(&[Modifiers::SHIFT, Modifiers::CONTROL]);
+    /// assert_eq!(modifiers.display(shift_first).to_string(), "Shift+Control");
+    /// assert_eq!(modifiers.to_string(), "Control+Shift");
+    /// ```
+    pub fn display<'a>(&self, order: ModifierOrder<'a>) -> impl fmt::Display + 'a {
+        DisplayModifiers {
+            modifiers: *self,
+            order,
+        }
+    }
+
+    /// Parse the name of a single modifier.
+    ///
+    /// Accepts the names written by `Display` and common aliases,
+    /// ignoring case:
+    ///
+    /// | Flag        | Names                                         |
+    /// |-------------|-----------------------------------------------|
+    /// | `CONTROL`   | `ctrl`, `control`                             |
+    /// | `ALT`       | `alt`, `opt`, `option`                        |
+    /// | `META`      | `meta`, `cmd`, `command`, `win`, `super`      |
+    /// | `ALT_GRAPH` | `altgr`, `altgraph`                           |
+    ///
+    /// As platforms usually report the Super key as Meta, `super` is
+    /// parsed as `META` in any case. `SUPER` is written by `Display` but
+    /// can't be parsed back. Unknown names return `None`.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::Modifiers;
+    /// assert_eq!(Modifiers::from_name("Cmd"), Some(Modifiers::META));
+    /// assert_eq!(Modifiers::from_name("CapsLock"), Some(Modifiers::CAPS_LOCK));
+    /// assert_eq!(Modifiers::from_name("Ctrl+Alt"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Modifiers> {
+        const ALIASES: &[(Modifiers, &str)] = &[
+            (Modifiers::CONTROL, "ctrl"),
+            (Modifiers::ALT, "opt"),
+            (Modifiers::ALT, "option"),
+            (Modifiers::META, "cmd"),
+            (Modifiers::META, "command"),
+            (Modifiers::META, "win"),
+            (Modifiers::META, "super"),
+            (Modifiers::ALT_GRAPH, "altgr"),
+        ];
+        ALIASES
+            .iter()
+            .chain(MODIFIER_NAMES)
+            .find(|(_, n)| n.eq_ignore_ascii_case(name))
+            .map(|(flag, _)| *flag)
+    }
+
+    /// Compute the ISO shift level selected by the modifiers.
+    ///
+    /// Shift selects level 2, AltGraph level 3 and both together level 4.
+    /// All other modifiers, including the lock keys, are ignored.
+    /// See [`Layout`](crate::Layout) for the meaning of the levels.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::Modifiers;
+    /// assert_eq!(Modifiers::empty().level(), 1);
+    /// assert_eq!(Modifiers::SHIFT.level(), 2);
+    /// assert_eq!(Modifiers::ALT_GRAPH.level(), 3);
+    /// assert_eq!((Modifiers::SHIFT | Modifiers::ALT_GRAPH).level(), 4);
+    /// ```
+    pub fn level(&self) -> u8 {
+        let mut level = 1;
+        if self.contains(Modifiers::SHIFT) {
+            level += 1;
+        }
+        if self.contains(Modifiers::ALT_GRAPH) {
+            level += 2;
+        }
+        level
+    }
+}
+
+struct DisplayModifiers<'a> {
+    modifiers: Modifiers,
+    order: ModifierOrder<'a>,
+}
+
+impl fmt::Display for DisplayModifiers<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut remaining = self.modifiers;
+        let listed = self.order.order.iter().flat_map(|item| {
+            MODIFIER_NAMES
+                .iter()
+                .filter(move |(flag, _)| item.contains(*flag))
+        });
+        for (flag, name) in listed.chain(MODIFIER_NAMES) {
+            if remaining.contains(*flag) {
+                if remaining != self.modifiers {
+                    f.write_str("+")?;
+                }
+                f.write_str(name)?;
+                remaining.remove(*flag);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Format the modifier names in the default order, like `Control+Shift`.
+impl fmt::Display for Modifiers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.display(ModifierOrder::DEFAULT).fmt(f)
+    }
+}
+
+/// Modifiers toggled by lock keys instead of being held down.
+pub(crate) const LOCKS: Modifiers = Modifiers::from_bits_truncate(
+    Modifiers::CAPS_LOCK.bits()
+        | Modifiers::FN_LOCK.bits()
+        | Modifiers::NUM_LOCK.bits()
+        | Modifiers::SCROLL_LOCK.bits()
+        | Modifiers::SYMBOL_LOCK.bits(),
+);
+
+impl Key<'_> {
+    /// Get the modifier flag set by this key.
+    ///
+    /// Returns `None` if the key is not a modifier or lock key.
+    pub fn modifier_flag(&self) -> Option<Modifiers> {
+        let flag = match self {
+            Key::Alt => Modifiers::ALT,
+            Key::AltGraph => Modifiers::ALT_GRAPH,
+            Key::CapsLock => Modifiers::CAPS_LOCK,
+            Key::Control => Modifiers::CONTROL,
+            Key::Fn => Modifiers::FN,
+            Key::FnLock => Modifiers::FN_LOCK,
+            Key::Meta => Modifiers::META,
+            Key::NumLock => Modifiers::NUM_LOCK,
+            Key::ScrollLock => Modifiers::SCROLL_LOCK,
+            Key::Shift => Modifiers::SHIFT,
+            Key::Symbol => Modifiers::SYMBOL,
+            Key::SymbolLock => Modifiers::SYMBOL_LOCK,
+            Key::Hyper => Modifiers::HYPER,
+            Key::Super => Modifiers::SUPER,
+            _ => return None,
+        };
+        Some(flag)
+    }
+}
+
+/// The boolean modifier attributes of a DOM keyboard event.
+///
+/// Specification: <https://w3c.github.io/uievents/#idl-keyboardevent>
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DomModifiers {
+    /// The *ctrlKey* attribute.
+    pub ctrl: bool,
+
+    /// The *shiftKey* attribute.
+    pub shift: bool,
+
+    /// The *altKey* attribute.
+    pub alt: bool,
+
+    /// The *metaKey* attribute.
+    pub meta: bool,
+
+    /// The AltGraph modifier, queried with `getModifierState("AltGraph")`.
+    pub alt_graph: bool,
+}
+
+impl Modifiers {
+    /// Create modifiers from the DOM modifier attributes.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::Modifiers;
+    /// let modifiers = Modifiers::from_dom(true, true, false, false, false);
+    /// assert_eq!(modifiers, Modifiers::CONTROL | Modifiers::SHIFT);
+    /// assert!(modifiers.to_dom().ctrl);
+    /// ```
+    pub fn from_dom(ctrl: bool, shift: bool, alt: bool, meta: bool, alt_graph: bool) -> Modifiers {
+        let mut modifiers = Modifiers::empty();
+        modifiers.set(Modifiers::CONTROL, ctrl);
+        modifiers.set(Modifiers::SHIFT, shift);
+        modifiers.set(Modifiers::ALT, alt);
+        modifiers.set(Modifiers::META, meta);
+        modifiers.set(Modifiers::ALT_GRAPH, alt_graph);
+        modifiers
+    }
+
+    /// Get the DOM modifier attributes.
+    ///
+    /// All other modifiers are discarded.
+    pub fn to_dom(&self) -> DomModifiers {
+        DomModifiers {
+            ctrl: self.contains(Modifiers::CONTROL),
+            shift: self.contains(Modifiers::SHIFT),
+            alt: self.contains(Modifiers::ALT),
+            meta: self.contains(Modifiers::META),
+            alt_graph: self.contains(Modifiers::ALT_GRAPH),
+        }
+    }
+
+    /// Remove the lock states like CapsLock and NumLock.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::Modifiers;
+    /// let modifiers = Modifiers::CONTROL | Modifiers::CAPS_LOCK;
+    /// assert_eq!(modifiers.without_locks(), Modifiers::CONTROL);
+    /// ```
+    pub fn without_locks(&self) -> Modifiers {
+        *self - LOCKS
+    }
+
+    /// Keep only the Control, Alt, Shift, Meta and AltGraph modifiers.
+    ///
+    /// These are the modifiers usually compared for shortcuts.
+    pub fn active(&self) -> Modifiers {
+        *self
+            & (Modifiers::CONTROL
+                | Modifiers::ALT
+                | Modifiers::SHIFT
+                | Modifiers::META
+                | Modifiers::ALT_GRAPH)
+    }
+}
+
+impl KeyboardEvent<'_> {
+    /// Query the state of a modifier by its DOM name like `"CapsLock"`.
+    ///
+    /// Unknown names return false.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::{KeyboardEvent, Modifiers};
+    /// let event = KeyboardEvent {
+    ///     modifiers: Modifiers::SHIFT | Modifiers::CAPS_LOCK,
+    ///     ..Default::default()
+    /// };
+    /// assert!(event.modifier_state("CapsLock"));
+    /// assert!(!event.modifier_state("Control"));
+    /// ```
+    ///
+    /// Specification: <https://w3c.github.io/uievents/#dom-keyboardevent-getmodifierstate>
+    pub fn modifier_state(&self, name: &str) -> bool {
+        MODIFIER_NAMES
+            .iter()
+            .any(|(flag, n)| *n == name && self.modifiers.contains(*flag))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use std::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_display_order() {
+        assert_eq!(
+            Modifiers::all().to_string(),
+            "Control+Alt+Shift+Meta+AltGraph+Fn+Symbol+Hyper+Super\
+             +CapsLock+FnLock+NumLock+ScrollLock+SymbolLock"
+        );
+        assert_eq!(Modifiers::empty().to_string(), "");
+    }
+
+    #[test]
+    fn test_display_combined_order() {
+        let items = [
+            Modifiers::empty(),
+            Modifiers::SHIFT,
+            Modifiers::META | Modifiers::CONTROL,
+        ];
+        let modifiers = Modifiers::CONTROL | Modifiers::SHIFT | Modifiers::META | Modifiers::ALT;
+        assert_eq!(
+            modifiers.display(ModifierOrder::new(&items)).to_string(),
+            "Shift+Control+Meta+Alt"
+        );
+        let items = [Modifiers::empty()];
+        let order = ModifierOrder::new(&items);
+        assert_eq!(Modifiers::SHIFT.display(order).to_string(), "Shift");
+    }
+
+    #[test]
+    fn test_from_name() {
+        for name in &["ctrl", "Ctrl", "control", "CONTROL"] {
+            assert_eq!(Modifiers::from_name(name), Some(Modifiers::CONTROL));
+        }
+        for name in &["opt", "option", "alt", "Alt"] {
+            assert_eq!(Modifiers::from_name(name), Some(Modifiers::ALT));
+        }
+        for name in &["cmd", "command", "win", "super", "Super", "SUPER", "meta"] {
+            assert_eq!(Modifiers::from_name(name), Some(Modifiers::META));
+        }
+        for name in &["altgr", "AltGr", "altgraph", "AltGraph"] {
+            assert_eq!(Modifiers::from_name(name), Some(Modifiers::ALT_GRAPH));
+        }
+        assert_eq!(Modifiers::from_name("shift"), Some(Modifiers::SHIFT));
+        assert_eq!(Modifiers::from_name("numlock"), Some(Modifiers::NUM_LOCK));
+        assert_eq!(Modifiers::from_name("hyperr"), None);
+        assert_eq!(Modifiers::from_name(""), None);
+    }
+
+    #[test]
+    fn test_from_name_round_trip() {
+        for (flag, name) in MODIFIER_NAMES {
+            if *flag == Modifiers::SUPER {
+                continue;
+            }
+            assert_eq!(Modifiers::from_name(&flag.to_string()), Some(*flag));
+            assert_eq!(Modifiers::from_name(name), Some(*flag));
+        }
+    }
+
+    #[test]
+    fn test_normalize() {
+        let all = Modifiers::all();
+        assert_eq!(
+            all.without_locks().to_string(),
+            "Control+Alt+Shift+Meta+AltGraph+Fn+Symbol+Hyper+Super"
+        );
+        assert_eq!(all.active().to_string(), "Control+Alt+Shift+Meta+AltGraph");
+        assert_eq!(all.active(), all.without_locks().active());
+        assert_eq!(LOCKS.without_locks(), Modifiers::empty());
+    }
+}
@@ -0,0 +1,195 @@
+//! Serialize [`Modifiers`] as an array of modifier names.
+//!
+//! The default serde representation of [`Modifiers`] is the raw bit
+//! value. Use this module with `#[serde(with = "...")]` to store the
+//! pressed modifiers as a list of names like `["Control", "Shift"]`
+//! instead. The names are the same as the ones accepted by
+//! `getModifierState` in the DOM and are emitted in bit order.
+//!
+//! ```rust
+//! # use gluten_keyboard::Modifiers;
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Binding {
+//!     #[serde(with = "gluten_keyboard::modifiers_array")]
+//!     modifiers: Modifiers,
+//! }
+//! ```
+//!
+//! Deserialization fails on unknown names. The [`lenient`] variant
+//! skips them instead.
+
+use core::fmt;
+
+use serde::de::{self, DeserializeSeed, Deserializer, SeqAccess, Unexpected, Visitor};
+use serde::ser::Serializer;
+
+use crate::modifiers::MODIFIER_NAMES;
+use crate::Modifiers;
+
+/// Serialize the modifiers as an array of names.
+pub fn serialize<S>(modifiers: &Modifiers, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(
+        MODIFIER_NAMES
+            .iter()
+            .filter(|(flag, _)| modifiers.contains(*flag))
+            .map(|(_, name)| name),
+    )
+}
+
+/// Deserialize the modifiers from an array of names.
+///
+/// Unknown names are an error.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Modifiers, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_seq(ModifiersVisitor {
+        ignore_unknown: false,
+    })
+}
+
+/// Like the parent module but ignores unknown modifier names.
+///
+/// Useful to read logs written by newer versions or other tools
+/// knowing more modifiers.
+pub mod lenient {
+    use serde::de::Deserializer;
+    use serde::ser::Serializer;
+
+    use super::ModifiersVisitor;
+    use crate::Modifiers;
+
+    /// Serialize the modifiers as an array of names.
+    pub fn serialize<S>(modifiers: &Modifiers, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize(modifiers, serializer)
+    }
+
+    /// Deserialize the modifiers from an array of names.
+    ///
+    /// Unknown names are skipped.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Modifiers, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ModifiersVisitor {
+            ignore_unknown: true,
+        })
+    }
+}
+
+struct ModifiersVisitor {
+    ignore_unknown: bool,
+}
+
+impl<'de> Visitor<'de> for ModifiersVisitor {
+    type Value = Modifiers;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an array of modifier names")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Modifiers, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut modifiers = Modifiers::empty();
+        while let Some(flag) = seq.next_element_seed(ModifierName {
+            ignore_unknown: self.ignore_unknown,
+        })? {
+            modifiers |= flag;
+        }
+        Ok(modifiers)
+    }
+}
+
+/// A single modifier name, unknown names map to no flag if ignored.
+struct ModifierName {
+    ignore_unknown: bool,
+}
+
+impl<'de> DeserializeSeed<'de> for ModifierName {
+    type Value = Modifiers;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Modifiers, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ModifierName {
+    type Value = Modifiers;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a modifier name")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Modifiers, E>
+    where
+        E: de::Error,
+    {
+        match MODIFIER_NAMES.iter().find(|(_, name)| *name == v) {
+            Some((flag, _)) => Ok(*flag),
+            None if self.ignore_unknown => Ok(Modifiers::empty()),
+            None => Err(E::invalid_value(Unexpected::Str(v), &self)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::de::value::{Error, SeqDeserializer};
+    use serde::{Deserialize, Serialize};
+
+    use crate::Modifiers;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Binding {
+        #[serde(with = "crate::modifiers_array")]
+        modifiers: Modifiers,
+    }
+
+    fn names(
+        names: &'static [&'static str],
+    ) -> SeqDeserializer<core::iter::Copied<core::slice::Iter<'static, &'static str>>, Error> {
+        SeqDeserializer::new(names.iter().copied())
+    }
+
+    #[test]
+    fn test_deserialize() {
+        assert_eq!(
+            super::deserialize(names(&["Control", "Shift"])).unwrap(),
+            Modifiers::CONTROL | Modifiers::SHIFT
+        );
+        assert!(super::deserialize(names(&["Control", "Ctrl"])).is_err());
+        assert_eq!(
+            super::lenient::deserialize(names(&["Control", "Ctrl"])).unwrap(),
+            Modifiers::CONTROL
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let cases = [
+            (
+                Modifiers::SHIFT | Modifiers::CONTROL | Modifiers::ALT,
+                r#"{"modifiers":["Alt","Control","Shift"]}"#,
+            ),
+            (Modifiers::empty(), r#"{"modifiers":[]}"#),
+        ];
+        for (modifiers, json) in cases.iter() {
+            let binding = Binding {
+                modifiers: *modifiers,
+            };
+            assert_eq!(serde_json::to_string(&binding).unwrap(), *json);
+            assert_eq!(serde_json::from_str::<Binding>(json).unwrap(), binding);
+        }
+    }
+}
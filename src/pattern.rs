@@ -0,0 +1,241 @@
+use crate::modifiers::LOCKS;
+use crate::{Key, KeyState, KeyboardEvent, Modifiers};
+
+/// Condition on the modifiers of a [`KeyPattern`].
+///
+/// Lock states like CapsLock are never taken into account.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ModifierMatch {
+    /// Exactly these modifiers are held.
+    Exact(Modifiers),
+
+    /// At least these modifiers are held, others may be held too.
+    AtLeast(Modifiers),
+
+    /// The modifiers are ignored.
+    Any,
+}
+
+/// Condition on the key of a [`KeyPattern`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+pub enum KeyMatch<'a> {
+    /// The key is this key.
+    ///
+    /// ASCII letters are compared ignoring case.
+    Is(Key<'a>),
+
+    /// The key has any character value.
+    AnyChar,
+
+    /// Any key, including modifier keys.
+    Any,
+}
+
+/// A declarative description of keyboard shortcuts.
+///
+/// Unlike the [`ShortcutMatcher`](crate::ShortcutMatcher) a pattern is a
+/// plain value which can be stored in configuration files. Patterns can
+/// also describe whole classes of shortcuts, like any key with Control held,
+/// or any key at all to record the next key press.
+///
+/// ```rust
+/// # use gluten_keyboard::{Key, KeyboardEvent, KeyMatch, KeyPattern, ModifierMatch, Modifiers};
+/// let save = KeyPattern {
+///     modifiers: ModifierMatch::Exact(Modifiers::CONTROL),
+///     key: KeyMatch::Is(Key::Character("s")),
+/// };
+/// let event = KeyboardEvent {
+///     key: Key::Character("s"),
+///     modifiers: Modifiers::CONTROL | Modifiers::CAPS_LOCK,
+///     ..Default::default()
+/// };
+/// assert!(save.matches(&event));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+pub struct KeyPattern<'a> {
+    /// Condition on the held modifiers.
+    pub modifiers: ModifierMatch,
+
+    /// Condition on the pressed key.
+    pub key: KeyMatch<'a>,
+}
+
+impl KeyPattern<'_> {
+    /// A pattern matching any key press.
+    pub const ANY: KeyPattern<'static> = KeyPattern {
+        modifiers: ModifierMatch::Any,
+        key: KeyMatch::Any,
+    };
+
+    /// Check if the event matches the pattern.
+    ///
+    /// Only key presses are matched, key releases never match.
+    pub fn matches(&self, event: &KeyboardEvent) -> bool {
+        event.state == KeyState::Down
+            && self.modifiers.matches(event.modifiers)
+            && self.key.matches(&event.key)
+    }
+
+    /// Compute a fingerprint to detect conflicting patterns.
+    ///
+    /// Patterns matching the same events have the same fingerprint,
+    /// as lock states and the case of ASCII letters are ignored.
+    /// The value is computed with the FNV-1a hash and is the same
+    /// across runs, platforms and versions of this crate.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::{Key, KeyMatch, KeyPattern, ModifierMatch, Modifiers};
+    /// let a = KeyPattern {
+    ///     modifiers: ModifierMatch::Exact(Modifiers::CONTROL),
+    ///     key: KeyMatch::Is(Key::Character("s")),
+    /// };
+    /// let b = KeyPattern {
+    ///     modifiers: ModifierMatch::Exact(Modifiers::CONTROL | Modifiers::NUM_LOCK),
+    ///     key: KeyMatch::Is(Key::Character("S")),
+    /// };
+    /// assert_eq!(a.fingerprint(), b.fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        let mut hash = Fnv::new();
+        match self.modifiers {
+            ModifierMatch::Exact(modifiers) => {
+                hash.write(&[0]);
+                hash.write(&(modifiers - LOCKS).bits().to_le_bytes());
+            }
+            // Requiring at least no modifiers matches like `Any`.
+            ModifierMatch::AtLeast(modifiers) if (modifiers - LOCKS).is_empty() => hash.write(&[2]),
+            ModifierMatch::AtLeast(modifiers) => {
+                hash.write(&[1]);
+                hash.write(&(modifiers - LOCKS).bits().to_le_bytes());
+            }
+            ModifierMatch::Any => hash.write(&[2]),
+        }
+        match self.key {
+            KeyMatch::Is(Key::Character(s)) => {
+                hash.write(&[0]);
+                for byte in s.bytes() {
+                    hash.write(&[byte.to_ascii_lowercase()]);
+                }
+            }
+            KeyMatch::Is(key) => {
+                hash.write(&[1]);
+                hash.write(key.telemetry_name().as_bytes());
+            }
+            KeyMatch::AnyChar => hash.write(&[2]),
+            KeyMatch::Any => hash.write(&[3]),
+        }
+        hash.0
+    }
+}
+
+/// The 64 bit FNV-1a hash function.
+struct Fnv(u64);
+
+impl Fnv {
+    fn new() -> Fnv {
+        Fnv(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+impl ModifierMatch {
+    /// Check if the modifiers fulfill the condition.
+    pub fn matches(&self, modifiers: Modifiers) -> bool {
+        let modifiers = modifiers - LOCKS;
+        match self {
+            ModifierMatch::Exact(expected) => modifiers == *expected - LOCKS,
+            ModifierMatch::AtLeast(expected) => modifiers.contains(*expected - LOCKS),
+            ModifierMatch::Any => true,
+        }
+    }
+}
+
+impl KeyMatch<'_> {
+    /// Check if the key fulfills the condition.
+    pub fn matches(&self, key: &Key) -> bool {
+        match (self, key) {
+            (KeyMatch::Is(Key::Character(expected)), Key::Character(actual)) => {
+                expected.eq_ignore_ascii_case(actual)
+            }
+            (KeyMatch::Is(expected), actual) => expected == actual,
+            (KeyMatch::AnyChar, actual) => matches!(actual, Key::Character(_)),
+            (KeyMatch::Any, _) => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn event(key: Key<'static>, modifiers: Modifiers) -> KeyboardEvent<'static> {
+        KeyboardEvent {
+            key,
+            modifiers,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_matches() {
+        let ctrl_any_char = KeyPattern {
+            modifiers: ModifierMatch::AtLeast(Modifiers::CONTROL),
+            key: KeyMatch::AnyChar,
+        };
+        let ctrl_shift_x = event(Key::Character("X"), Modifiers::CONTROL | Modifiers::SHIFT);
+        assert!(ctrl_any_char.matches(&ctrl_shift_x));
+        assert!(!ctrl_any_char.matches(&event(Key::Enter, Modifiers::CONTROL)));
+        assert!(!ctrl_any_char.matches(&event(Key::Character("x"), Modifiers::empty())));
+
+        let exact = KeyPattern {
+            modifiers: ModifierMatch::Exact(Modifiers::CONTROL),
+            key: KeyMatch::Is(Key::Character("x")),
+        };
+        assert!(!exact.matches(&ctrl_shift_x));
+        assert!(exact.matches(&event(Key::Character("X"), Modifiers::CONTROL)));
+
+        assert!(KeyPattern::ANY.matches(&event(Key::Shift, Modifiers::SHIFT)));
+        let mut release = ctrl_shift_x;
+        release.state = KeyState::Up;
+        assert!(!KeyPattern::ANY.matches(&release));
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        // Pin the value so it stays stable across versions.
+        assert_eq!(KeyPattern::ANY.fingerprint(), 0x0839_5507_b4f1_37f2);
+        let enter = KeyPattern {
+            modifiers: ModifierMatch::Any,
+            key: KeyMatch::Is(Key::Enter),
+        };
+        assert_ne!(enter.fingerprint(), KeyPattern::ANY.fingerprint());
+        let exact = KeyPattern {
+            modifiers: ModifierMatch::Exact(Modifiers::empty()),
+            key: KeyMatch::Any,
+        };
+        assert_ne!(exact.fingerprint(), KeyPattern::ANY.fingerprint());
+        for modifiers in &[Modifiers::empty(), Modifiers::NUM_LOCK] {
+            let at_least = KeyPattern {
+                modifiers: ModifierMatch::AtLeast(*modifiers),
+                key: KeyMatch::Any,
+            };
+            assert_eq!(at_least.fingerprint(), KeyPattern::ANY.fingerprint());
+        }
+        let shift = KeyPattern {
+            modifiers: ModifierMatch::AtLeast(Modifiers::SHIFT),
+            key: KeyMatch::Any,
+        };
+        assert_ne!(shift.fingerprint(), KeyPattern::ANY.fingerprint());
+    }
+}
@@ -0,0 +1,114 @@
+use crate::modifiers::LOCKS;
+use crate::{Code, KeyState, KeyboardEvent, Layout, Location};
+
+impl<'a> KeyboardEvent<'a> {
+    /// Move the event to a different physical key.
+    ///
+    /// The mapping returns the new code for a code, or `None` to keep the
+    /// code, so any map can be used like `|code| map.get(&code).copied()`.
+    /// If the mapping returns a code, a new event for the
+    /// mapped code is returned. Its key is resolved with the layout using
+    /// the current modifiers and its location is derived from the new code,
+    /// so the legacy key codes follow as well. If the old or new key is a
+    /// held modifier like Shift the modifier flags are updated accordingly.
+    ///
+    /// Events for codes not in the mapping are returned unchanged.
+    ///
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use gluten_keyboard::{Code, Key, KeyboardEvent, Location, Modifiers, UsQwerty};
+    /// let mut mapping = HashMap::new();
+    /// mapping.insert(Code::CapsLock, Code::ControlLeft);
+    /// let event = KeyboardEvent {
+    ///     key: Key::CapsLock,
+    ///     code: Code::CapsLock,
+    ///     ..Default::default()
+    /// };
+    /// let remapped = event.remap_code(|code| mapping.get(&code).copied(), &UsQwerty);
+    /// assert_eq!(remapped.key, Key::Control);
+    /// assert_eq!(remapped.location, Location::Left);
+    /// assert_eq!(remapped.modifiers, Modifiers::CONTROL);
+    /// ```
+    pub fn remap_code<'b, M>(&self, mapping: M, layout: &'b dyn Layout) -> KeyboardEvent<'b>
+    where
+        'a: 'b,
+        M: Fn(Code) -> Option<Code>,
+    {
+        let code = match mapping(self.code) {
+            Some(code) => code,
+            None => return self.clone(),
+        };
+        let mut modifiers = self.modifiers;
+        if let Some(flag) = self.key.modifier_flag() {
+            if !LOCKS.contains(flag) {
+                modifiers.remove(flag);
+            }
+        }
+        let key = layout.resolve(code, modifiers);
+        if let Some(flag) = key.modifier_flag() {
+            if !LOCKS.contains(flag) {
+                modifiers.set(flag, self.state == KeyState::Down);
+            }
+        }
+        KeyboardEvent {
+            key,
+            code,
+            location: Location::from_code(code),
+            modifiers,
+            ..self.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Key, Modifiers, UsQwerty};
+
+    fn swap(code: Code) -> Option<Code> {
+        match code {
+            Code::CapsLock => Some(Code::ControlLeft),
+            Code::ShiftLeft => Some(Code::KeyA),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_modifier_up() {
+        let event = KeyboardEvent {
+            state: KeyState::Up,
+            key: Key::CapsLock,
+            code: Code::CapsLock,
+            modifiers: Modifiers::CONTROL | Modifiers::SHIFT,
+            ..Default::default()
+        };
+        let remapped = event.remap_code(swap, &UsQwerty);
+        assert_eq!(remapped.key, Key::Control);
+        assert_eq!(remapped.code, Code::ControlLeft);
+        assert_eq!(remapped.state, KeyState::Up);
+        assert_eq!(remapped.modifiers, Modifiers::SHIFT);
+    }
+
+    #[test]
+    fn test_modifier_to_letter() {
+        let mut event = KeyboardEvent {
+            key: Key::Shift,
+            code: Code::ShiftLeft,
+            location: Location::Left,
+            modifiers: Modifiers::SHIFT | Modifiers::CAPS_LOCK,
+            ..Default::default()
+        };
+        let remapped = event.remap_code(swap, &UsQwerty);
+        assert_eq!(remapped.key, Key::Character("a"));
+        assert_eq!(remapped.code, Code::KeyA);
+        assert_eq!(remapped.location, Location::Standard);
+        assert_eq!(remapped.modifiers, Modifiers::CAPS_LOCK);
+        event.state = KeyState::Up;
+        assert_eq!(
+            event.remap_code(swap, &UsQwerty).modifiers,
+            Modifiers::CAPS_LOCK
+        );
+        event.code = Code::KeyB;
+        assert_eq!(event.remap_code(swap, &UsQwerty), event);
+    }
+}
@@ -0,0 +1,341 @@
+//! A line based text format for recorded keyboard events.
+//!
+//! Each line describes one event:
+//!
+//! ```text
+//! <state> [<modifier>+]...<code> [<key>] [<location>] [repeat] [composing]
+//! ```
+//!
+//! - The state is `down` or `up`.
+//! - The modifiers are the names of the held modifier keys like `Control`
+//!   or `Shift`, each followed by a `+`. They are written in the
+//!   [default order](crate::ModifierOrder::DEFAULT) but may be in any order.
+//! - The code is the name of the physical key like `KeyS`.
+//! - The key is either a named key like `Enter` or a character in double
+//!   quotes like `"s"`. Inside the quotes `\"`, `\\` and `\n` stand for a
+//!   double quote, a backslash and a line feed, other backslashes are
+//!   invalid. If the key is missing it is unidentified.
+//! - The location is `standard`, `left`, `right` or `numpad`. It is only
+//!   needed if it differs from [`Location::from_code`].
+//! - `repeat` and `composing` set the corresponding flags.
+//!
+//! Empty lines and lines starting with `#` are ignored.
+//! The raw value of an unidentified key is not recorded.
+//!
+//! ```text
+//! # Save the file.
+//! down ControlLeft Control
+//! down Control+KeyS "s"
+//! up Control+KeyS "s"
+//! up ControlLeft Control
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use core::ops::Range;
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::modifiers::MODIFIER_NAMES;
+use crate::{Code, Key, KeyState, KeyboardEvent, Location, Modifiers};
+
+/// Write events to the replay format.
+///
+/// ```rust
+/// # use gluten_keyboard::{to_replay_string, Code, Key, KeyboardEvent, Modifiers};
+/// let event = KeyboardEvent {
+///     key: Key::Character("s"),
+///     code: Code::KeyS,
+///     modifiers: Modifiers::CONTROL,
+///     ..Default::default()
+/// };
+/// assert_eq!(to_replay_string(&[event]), "down Control+KeyS \"s\"\n");
+/// ```
+pub fn to_replay_string(events: &[KeyboardEvent]) -> String {
+    let mut out = String::new();
+    for event in events {
+        write_event(&mut out, event).expect("writing to a string failed");
+    }
+    out
+}
+
+fn write_event(out: &mut String, event: &KeyboardEvent) -> fmt::Result {
+    match event.state {
+        KeyState::Down => out.write_str("down ")?,
+        KeyState::Up => out.write_str("up ")?,
+    }
+    if !event.modifiers.is_empty() {
+        write!(out, "{}+", event.modifiers)?;
+    }
+    write!(out, "{}", event.code)?;
+    match event.key {
+        Key::Character(s) => {
+            out.write_str(" \"")?;
+            for c in s.chars() {
+                match c {
+                    '"' => out.write_str("\\\"")?,
+                    '\\' => out.write_str("\\\\")?,
+                    '\n' => out.write_str("\\n")?,
+                    c => out.write_char(c)?,
+                }
+            }
+            out.write_char('"')?;
+        }
+        Key::Unidentified(_) => {}
+        key => write!(out, " {}", key)?,
+    }
+    if event.location != Location::from_code(event.code) {
+        out.write_str(match event.location {
+            Location::Standard => " standard",
+            Location::Left => " left",
+            Location::Right => " right",
+            Location::Numpad => " numpad",
+        })?;
+    }
+    if event.repeat {
+        out.write_str(" repeat")?;
+    }
+    if event.is_composing {
+        out.write_str(" composing")?;
+    }
+    out.write_char('\n')
+}
+
+/// Read events from the replay format.
+///
+/// Character values borrow from the input string. Characters containing
+/// escapes are unescaped and appended to `buffer`, and borrow from it.
+///
+/// ```rust
+/// # use gluten_keyboard::{from_replay_string, Code, Key, KeyState, Modifiers};
+/// let mut buffer = String::new();
+/// let input = "down Control+KeyS \"s\"\nup Quote \"\\\"\"\n";
+/// let events = from_replay_string(input, &mut buffer).unwrap();
+/// assert_eq!(events.len(), 2);
+/// assert_eq!(events[0].key, Key::Character("s"));
+/// assert_eq!(events[0].modifiers, Modifiers::CONTROL);
+/// assert_eq!(events[1].state, KeyState::Up);
+/// assert_eq!(events[1].key, Key::Character("\""));
+/// ```
+pub fn from_replay_string<'a>(
+    s: &'a str,
+    buffer: &'a mut String,
+) -> Result<Vec<KeyboardEvent<'a>>, ReplayError> {
+    let mut events = Vec::new();
+    for (index, line) in s.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let event = parse_event(line, buffer).ok_or(ReplayError { line: index + 1 })?;
+        events.push(event);
+    }
+    let buffer: &'a str = buffer;
+    Ok(events
+        .into_iter()
+        .map(|(event, unescaped)| match unescaped {
+            Some(range) => KeyboardEvent {
+                key: Key::Character(&buffer[range]),
+                ..event
+            },
+            None => event,
+        })
+        .collect())
+}
+
+/// Parse one line, returning the range of the unescaped character in the
+/// buffer if the character had escapes.
+fn parse_event<'a>(
+    line: &'a str,
+    buffer: &mut String,
+) -> Option<(KeyboardEvent<'a>, Option<Range<usize>>)> {
+    let (state, rest) = split_token(line);
+    let state = match state {
+        "down" => KeyState::Down,
+        "up" => KeyState::Up,
+        _ => return None,
+    };
+    let (keys, mut rest) = split_token(rest);
+    let mut names = keys.split('+');
+    let code: Code = names.next_back()?.parse().ok()?;
+    let mut modifiers = Modifiers::empty();
+    for name in names {
+        let (flag, _) = MODIFIER_NAMES.iter().find(|(_, n)| *n == name)?;
+        modifiers |= *flag;
+    }
+    let mut event = KeyboardEvent {
+        state,
+        key: Key::Unidentified(None),
+        code,
+        location: Location::from_code(code),
+        modifiers,
+        ..Default::default()
+    };
+    let mut unescaped = None;
+    if let Some(quoted) = rest.trim_start().strip_prefix('"') {
+        let bytes = quoted.as_bytes();
+        let mut end = 0;
+        let mut escaped = false;
+        loop {
+            match *bytes.get(end)? {
+                b'"' => break,
+                b'\\' => {
+                    escaped = true;
+                    end += 2;
+                }
+                _ => end += 1,
+            }
+        }
+        // Escaped characters are replaced once all lines are parsed.
+        event.key = Key::Character(if escaped { "" } else { &quoted[..end] });
+        if escaped {
+            let start = buffer.len();
+            let mut chars = quoted[..end].chars();
+            while let Some(c) = chars.next() {
+                buffer.push(match c {
+                    '\\' => match chars.next()? {
+                        '"' => '"',
+                        '\\' => '\\',
+                        'n' => '\n',
+                        _ => return None,
+                    },
+                    c => c,
+                });
+            }
+            unescaped = Some(start..buffer.len());
+        }
+        rest = &quoted[end + 1..];
+    }
+    loop {
+        let (token, remainder) = split_token(rest);
+        rest = remainder;
+        match token {
+            "" => break,
+            "standard" => event.location = Location::Standard,
+            "left" => event.location = Location::Left,
+            "right" => event.location = Location::Right,
+            "numpad" => event.location = Location::Numpad,
+            "repeat" => event.repeat = true,
+            "composing" => event.is_composing = true,
+            _ if event.key == Key::Unidentified(None) => event.key = Key::parse(token).ok()?,
+            _ => return None,
+        }
+    }
+    Some((event, unescaped))
+}
+
+/// Split off the first whitespace separated token.
+fn split_token(s: &str) -> (&str, &str) {
+    let s = s.trim_start();
+    match s.find(char::is_whitespace) {
+        Some(index) => (&s[..index], &s[index..]),
+        None => (s, ""),
+    }
+}
+
+/// Error returned for malformed replay input.
+#[derive(Clone, Debug)]
+pub struct ReplayError {
+    line: usize,
+}
+
+impl ReplayError {
+    /// The line number of the malformed event, starting from one.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid replay event on line {}", self.line)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ReplayError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let events = [
+            KeyboardEvent {
+                key: Key::Character(""),
+                code: Code::Unidentified,
+                ..Default::default()
+            },
+            KeyboardEvent {
+                key: Key::Character("\n"),
+                code: Code::Enter,
+                ..Default::default()
+            },
+            KeyboardEvent {
+                key: Key::Character("a\"b"),
+                code: Code::KeyA,
+                ..Default::default()
+            },
+            KeyboardEvent {
+                key: Key::Character("\\"),
+                code: Code::Backslash,
+                ..Default::default()
+            },
+            KeyboardEvent {
+                key: Key::Shift,
+                code: Code::ShiftLeft,
+                location: Location::Left,
+                modifiers: Modifiers::SHIFT,
+                ..Default::default()
+            },
+            KeyboardEvent {
+                key: Key::Character("\""),
+                code: Code::Quote,
+                modifiers: Modifiers::SHIFT | Modifiers::CAPS_LOCK,
+                repeat: true,
+                ..Default::default()
+            },
+            KeyboardEvent {
+                key: Key::Character(" "),
+                code: Code::Space,
+                location: Location::Numpad,
+                is_composing: true,
+                ..Default::default()
+            },
+            KeyboardEvent {
+                state: KeyState::Up,
+                code: Code::ShiftLeft,
+                location: Location::Left,
+                ..Default::default()
+            },
+        ];
+        let text = to_replay_string(&events);
+        assert!(text.starts_with("down Unidentified \"\"\ndown Enter \"\\n\"\n"));
+        let mut buffer = String::new();
+        assert_eq!(from_replay_string(&text, &mut buffer).unwrap(), events);
+        assert_eq!(buffer, "\na\"b\\\"");
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut buffer = String::new();
+        assert_eq!(
+            from_replay_string("\n# comment\n", &mut buffer).unwrap(),
+            []
+        );
+        assert_eq!(
+            from_replay_string("down KeyA\npress KeyA", &mut buffer)
+                .unwrap_err()
+                .line(),
+            2
+        );
+        assert!(from_replay_string("down Ctrl+KeyA", &mut buffer).is_err());
+        assert!(from_replay_string("down KeyA \"a", &mut buffer).is_err());
+        assert!(from_replay_string("down KeyA Enter Tab", &mut buffer).is_err());
+        assert!(from_replay_string("down KeyA \"\\a\"", &mut buffer).is_err());
+        assert!(from_replay_string("down KeyA \"\\\"", &mut buffer).is_err());
+    }
+}
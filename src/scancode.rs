@@ -0,0 +1,656 @@
+//! Conversions between `Code` and platform scancodes.
+
+use crate::table::{lookup, reverse_lookup, Table};
+use crate::Code;
+
+impl Code {
+    /// Convert a Linux evdev key code to a `Code`.
+    ///
+    /// The values are the `KEY_*` constants from `linux/input-event-codes.h`.
+    /// X11 keycodes are the evdev codes plus 8.
+    /// Unknown codes return `Code::Unidentified`.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::Code;
+    /// assert_eq!(Code::from_evdev(30), Code::KeyA);
+    /// assert_eq!(Code::KeyA.to_evdev(), Some(30));
+    /// ```
+    pub fn from_evdev(code: u32) -> Code {
+        lookup(EVDEV, code)
+    }
+
+    /// Convert the `Code` to a Linux evdev key code.
+    pub fn to_evdev(&self) -> Option<u32> {
+        reverse_lookup(EVDEV, *self)
+    }
+
+    /// Convert a USB HID usage ID on the keyboard page (0x07) to a `Code`.
+    ///
+    /// Unknown usages return `Code::Unidentified`.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::Code;
+    /// assert_eq!(Code::from_hid_usage(0x04), Code::KeyA);
+    /// assert_eq!(Code::KeyA.to_hid_usage(), Some(0x04));
+    /// ```
+    pub fn from_hid_usage(usage: u16) -> Code {
+        lookup(HID_KEYBOARD, usage.into())
+    }
+
+    /// Convert the `Code` to a USB HID usage ID on the keyboard page (0x07).
+    ///
+    /// Returns `None` for keys not on the keyboard page.
+    pub fn to_hid_usage(&self) -> Option<u16> {
+        reverse_lookup(HID_KEYBOARD, *self).map(|usage| usage as u16)
+    }
+
+    /// Convert a USB HID usage page and ID to a `Code`.
+    ///
+    /// Besides the keyboard page (0x07) supports the system controls
+    /// of the generic desktop page (0x01) and the media keys of the
+    /// consumer page (0x0C). Unknown usages return `Code::Unidentified`.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::Code;
+    /// assert_eq!(Code::from_hid_usage_full(0x07, 0x04), Code::KeyA);
+    /// assert_eq!(Code::from_hid_usage_full(0x0C, 0xCD), Code::MediaPlayPause);
+    /// assert_eq!(Code::Sleep.to_hid_usage_full(), Some((0x01, 0x82)));
+    /// ```
+    pub fn from_hid_usage_full(page: u16, usage: u16) -> Code {
+        match page {
+            0x01 => lookup(HID_GENERIC_DESKTOP, usage.into()),
+            0x07 => lookup(HID_KEYBOARD, usage.into()),
+            0x0C => lookup(HID_CONSUMER, usage.into()),
+            _ => Code::Unidentified,
+        }
+    }
+
+    /// Convert the `Code` to a USB HID usage page and ID.
+    ///
+    /// Keys with usages on multiple pages, like Power or the volume keys,
+    /// report the generic desktop or consumer usage as operating systems
+    /// support those better than the keyboard page usage.
+    pub fn to_hid_usage_full(&self) -> Option<(u16, u16)> {
+        let pages = [
+            (0x01, HID_GENERIC_DESKTOP),
+            (0x0C, HID_CONSUMER),
+            (0x07, HID_KEYBOARD),
+        ];
+        pages.iter().find_map(|(page, table)| {
+            reverse_lookup(table, *self).map(|usage| (*page, usage as u16))
+        })
+    }
+}
+
+impl Code {
+    /// Guess the physical key from a legacy DOM *keyCode* value.
+    ///
+    /// For letters and digits the *keyCode* does not depend on the layout
+    /// and identifies the key position on a US keyboard, e.g. 65 is `KeyA`
+    /// and 49 is `Digit1`. The punctuation key codes 186 to 222 are mapped
+    /// to their US keyboard positions as well.
+    ///
+    /// Some key codes are shared by multiple keys and return
+    /// `Code::Unidentified`: 16 (Shift), 17 (Control) and 18 (Alt)
+    /// don't tell the left from the right key.
+    /// 13 is mapped to `Enter` although the numpad Enter key uses it too.
+    ///
+    /// 91 and 92 are the left and right Windows keys (`VK_LWIN` and
+    /// `VK_RWIN`) and map to `MetaLeft` and `MetaRight`. 93 is the
+    /// Windows menu key (`VK_APPS`) and maps to `ContextMenu`, which
+    /// is ambiguous on macOS: WebKit and Blink report 91 for the left
+    /// and 93 for the right Command key.
+    ///
+    /// Specification: <https://w3c.github.io/uievents/#fixed-virtual-key-codes>
+    pub fn from_legacy_keycode(code: u32) -> Code {
+        lookup(LEGACY_KEYCODE, code)
+    }
+}
+
+/// Legacy DOM *keyCode* values.
+const LEGACY_KEYCODE: Table = &[
+    (8, Code::Backspace),
+    (9, Code::Tab),
+    (13, Code::Enter),
+    (19, Code::Pause),
+    (20, Code::CapsLock),
+    (27, Code::Escape),
+    (32, Code::Space),
+    (33, Code::PageUp),
+    (34, Code::PageDown),
+    (35, Code::End),
+    (36, Code::Home),
+    (37, Code::ArrowLeft),
+    (38, Code::ArrowUp),
+    (39, Code::ArrowRight),
+    (40, Code::ArrowDown),
+    (44, Code::PrintScreen),
+    (45, Code::Insert),
+    (46, Code::Delete),
+    (48, Code::Digit0),
+    (49, Code::Digit1),
+    (50, Code::Digit2),
+    (51, Code::Digit3),
+    (52, Code::Digit4),
+    (53, Code::Digit5),
+    (54, Code::Digit6),
+    (55, Code::Digit7),
+    (56, Code::Digit8),
+    (57, Code::Digit9),
+    (65, Code::KeyA),
+    (66, Code::KeyB),
+    (67, Code::KeyC),
+    (68, Code::KeyD),
+    (69, Code::KeyE),
+    (70, Code::KeyF),
+    (71, Code::KeyG),
+    (72, Code::KeyH),
+    (73, Code::KeyI),
+    (74, Code::KeyJ),
+    (75, Code::KeyK),
+    (76, Code::KeyL),
+    (77, Code::KeyM),
+    (78, Code::KeyN),
+    (79, Code::KeyO),
+    (80, Code::KeyP),
+    (81, Code::KeyQ),
+    (82, Code::KeyR),
+    (83, Code::KeyS),
+    (84, Code::KeyT),
+    (85, Code::KeyU),
+    (86, Code::KeyV),
+    (87, Code::KeyW),
+    (88, Code::KeyX),
+    (89, Code::KeyY),
+    (90, Code::KeyZ),
+    (91, Code::MetaLeft),
+    (92, Code::MetaRight),
+    (93, Code::ContextMenu),
+    (96, Code::Numpad0),
+    (97, Code::Numpad1),
+    (98, Code::Numpad2),
+    (99, Code::Numpad3),
+    (100, Code::Numpad4),
+    (101, Code::Numpad5),
+    (102, Code::Numpad6),
+    (103, Code::Numpad7),
+    (104, Code::Numpad8),
+    (105, Code::Numpad9),
+    (106, Code::NumpadMultiply),
+    (107, Code::NumpadAdd),
+    (109, Code::NumpadSubtract),
+    (110, Code::NumpadDecimal),
+    (111, Code::NumpadDivide),
+    (112, Code::F1),
+    (113, Code::F2),
+    (114, Code::F3),
+    (115, Code::F4),
+    (116, Code::F5),
+    (117, Code::F6),
+    (118, Code::F7),
+    (119, Code::F8),
+    (120, Code::F9),
+    (121, Code::F10),
+    (122, Code::F11),
+    (123, Code::F12),
+    (124, Code::F13),
+    (125, Code::F14),
+    (126, Code::F15),
+    (127, Code::F16),
+    (128, Code::F17),
+    (129, Code::F18),
+    (130, Code::F19),
+    (131, Code::F20),
+    (132, Code::F21),
+    (133, Code::F22),
+    (134, Code::F23),
+    (135, Code::F24),
+    (144, Code::NumLock),
+    (145, Code::ScrollLock),
+    (186, Code::Semicolon),
+    (187, Code::Equal),
+    (188, Code::Comma),
+    (189, Code::Minus),
+    (190, Code::Period),
+    (191, Code::Slash),
+    (192, Code::Backquote),
+    (219, Code::BracketLeft),
+    (220, Code::Backslash),
+    (221, Code::BracketRight),
+    (222, Code::Quote),
+];
+
+/// Linux evdev key codes.
+const EVDEV: Table = &[
+    (1, Code::Escape),
+    (2, Code::Digit1),
+    (3, Code::Digit2),
+    (4, Code::Digit3),
+    (5, Code::Digit4),
+    (6, Code::Digit5),
+    (7, Code::Digit6),
+    (8, Code::Digit7),
+    (9, Code::Digit8),
+    (10, Code::Digit9),
+    (11, Code::Digit0),
+    (12, Code::Minus),
+    (13, Code::Equal),
+    (14, Code::Backspace),
+    (15, Code::Tab),
+    (16, Code::KeyQ),
+    (17, Code::KeyW),
+    (18, Code::KeyE),
+    (19, Code::KeyR),
+    (20, Code::KeyT),
+    (21, Code::KeyY),
+    (22, Code::KeyU),
+    (23, Code::KeyI),
+    (24, Code::KeyO),
+    (25, Code::KeyP),
+    (26, Code::BracketLeft),
+    (27, Code::BracketRight),
+    (28, Code::Enter),
+    (29, Code::ControlLeft),
+    (30, Code::KeyA),
+    (31, Code::KeyS),
+    (32, Code::KeyD),
+    (33, Code::KeyF),
+    (34, Code::KeyG),
+    (35, Code::KeyH),
+    (36, Code::KeyJ),
+    (37, Code::KeyK),
+    (38, Code::KeyL),
+    (39, Code::Semicolon),
+    (40, Code::Quote),
+    (41, Code::Backquote),
+    (42, Code::ShiftLeft),
+    (43, Code::Backslash),
+    (44, Code::KeyZ),
+    (45, Code::KeyX),
+    (46, Code::KeyC),
+    (47, Code::KeyV),
+    (48, Code::KeyB),
+    (49, Code::KeyN),
+    (50, Code::KeyM),
+    (51, Code::Comma),
+    (52, Code::Period),
+    (53, Code::Slash),
+    (54, Code::ShiftRight),
+    (55, Code::NumpadMultiply),
+    (56, Code::AltLeft),
+    (57, Code::Space),
+    (58, Code::CapsLock),
+    (59, Code::F1),
+    (60, Code::F2),
+    (61, Code::F3),
+    (62, Code::F4),
+    (63, Code::F5),
+    (64, Code::F6),
+    (65, Code::F7),
+    (66, Code::F8),
+    (67, Code::F9),
+    (68, Code::F10),
+    (69, Code::NumLock),
+    (70, Code::ScrollLock),
+    (71, Code::Numpad7),
+    (72, Code::Numpad8),
+    (73, Code::Numpad9),
+    (74, Code::NumpadSubtract),
+    (75, Code::Numpad4),
+    (76, Code::Numpad5),
+    (77, Code::Numpad6),
+    (78, Code::NumpadAdd),
+    (79, Code::Numpad1),
+    (80, Code::Numpad2),
+    (81, Code::Numpad3),
+    (82, Code::Numpad0),
+    (83, Code::NumpadDecimal),
+    (86, Code::IntlBackslash),
+    (87, Code::F11),
+    (88, Code::F12),
+    (89, Code::IntlRo),
+    (90, Code::Katakana),
+    (91, Code::Hiragana),
+    (92, Code::Convert),
+    (93, Code::KanaMode),
+    (94, Code::NonConvert),
+    (96, Code::NumpadEnter),
+    (97, Code::ControlRight),
+    (98, Code::NumpadDivide),
+    (99, Code::PrintScreen),
+    (100, Code::AltRight),
+    (102, Code::Home),
+    (103, Code::ArrowUp),
+    (104, Code::PageUp),
+    (105, Code::ArrowLeft),
+    (106, Code::ArrowRight),
+    (107, Code::End),
+    (108, Code::ArrowDown),
+    (109, Code::PageDown),
+    (110, Code::Insert),
+    (111, Code::Delete),
+    (113, Code::AudioVolumeMute),
+    (114, Code::AudioVolumeDown),
+    (115, Code::AudioVolumeUp),
+    (116, Code::Power),
+    (117, Code::NumpadEqual),
+    (119, Code::Pause),
+    (121, Code::NumpadComma),
+    (122, Code::Lang1),
+    (123, Code::Lang2),
+    (124, Code::IntlYen),
+    (125, Code::MetaLeft),
+    (126, Code::MetaRight),
+    (127, Code::ContextMenu),
+    (128, Code::BrowserStop),
+    (129, Code::Again),
+    (130, Code::Props),
+    (131, Code::Undo),
+    (133, Code::Copy),
+    (134, Code::Open),
+    (135, Code::Paste),
+    (136, Code::Find),
+    (137, Code::Cut),
+    (138, Code::Help),
+    (140, Code::LaunchApp2),
+    (142, Code::Sleep),
+    (143, Code::WakeUp),
+    (155, Code::LaunchMail),
+    (156, Code::BrowserFavorites),
+    (157, Code::LaunchApp1),
+    (158, Code::BrowserBack),
+    (159, Code::BrowserForward),
+    (161, Code::Eject),
+    (163, Code::MediaTrackNext),
+    (164, Code::MediaPlayPause),
+    (165, Code::MediaTrackPrevious),
+    (166, Code::MediaStop),
+    (167, Code::MediaRecord),
+    (168, Code::MediaRewind),
+    (171, Code::MediaSelect),
+    (172, Code::BrowserHome),
+    (173, Code::BrowserRefresh),
+    (179, Code::NumpadParenLeft),
+    (180, Code::NumpadParenRight),
+    (183, Code::F13),
+    (184, Code::F14),
+    (185, Code::F15),
+    (186, Code::F16),
+    (187, Code::F17),
+    (188, Code::F18),
+    (189, Code::F19),
+    (190, Code::F20),
+    (191, Code::F21),
+    (192, Code::F22),
+    (193, Code::F23),
+    (194, Code::F24),
+    (200, Code::MediaPlay),
+    (201, Code::MediaPause),
+    (208, Code::MediaFastForward),
+    (217, Code::BrowserSearch),
+    (223, Code::Abort),
+    (224, Code::BrightnessDown),
+    (225, Code::BrightnessUp),
+    (231, Code::MailSend),
+    (232, Code::MailReply),
+    (233, Code::MailForward),
+    (248, Code::MicrophoneMuteToggle),
+    (464, Code::Fn),
+];
+
+/// USB HID usage IDs of the keyboard page (0x07).
+const HID_KEYBOARD: Table = &[
+    (0x04, Code::KeyA),
+    (0x05, Code::KeyB),
+    (0x06, Code::KeyC),
+    (0x07, Code::KeyD),
+    (0x08, Code::KeyE),
+    (0x09, Code::KeyF),
+    (0x0A, Code::KeyG),
+    (0x0B, Code::KeyH),
+    (0x0C, Code::KeyI),
+    (0x0D, Code::KeyJ),
+    (0x0E, Code::KeyK),
+    (0x0F, Code::KeyL),
+    (0x10, Code::KeyM),
+    (0x11, Code::KeyN),
+    (0x12, Code::KeyO),
+    (0x13, Code::KeyP),
+    (0x14, Code::KeyQ),
+    (0x15, Code::KeyR),
+    (0x16, Code::KeyS),
+    (0x17, Code::KeyT),
+    (0x18, Code::KeyU),
+    (0x19, Code::KeyV),
+    (0x1A, Code::KeyW),
+    (0x1B, Code::KeyX),
+    (0x1C, Code::KeyY),
+    (0x1D, Code::KeyZ),
+    (0x1E, Code::Digit1),
+    (0x1F, Code::Digit2),
+    (0x20, Code::Digit3),
+    (0x21, Code::Digit4),
+    (0x22, Code::Digit5),
+    (0x23, Code::Digit6),
+    (0x24, Code::Digit7),
+    (0x25, Code::Digit8),
+    (0x26, Code::Digit9),
+    (0x27, Code::Digit0),
+    (0x28, Code::Enter),
+    (0x29, Code::Escape),
+    (0x2A, Code::Backspace),
+    (0x2B, Code::Tab),
+    (0x2C, Code::Space),
+    (0x2D, Code::Minus),
+    (0x2E, Code::Equal),
+    (0x2F, Code::BracketLeft),
+    (0x30, Code::BracketRight),
+    (0x31, Code::Backslash),
+    (0x33, Code::Semicolon),
+    (0x34, Code::Quote),
+    (0x35, Code::Backquote),
+    (0x36, Code::Comma),
+    (0x37, Code::Period),
+    (0x38, Code::Slash),
+    (0x39, Code::CapsLock),
+    (0x3A, Code::F1),
+    (0x3B, Code::F2),
+    (0x3C, Code::F3),
+    (0x3D, Code::F4),
+    (0x3E, Code::F5),
+    (0x3F, Code::F6),
+    (0x40, Code::F7),
+    (0x41, Code::F8),
+    (0x42, Code::F9),
+    (0x43, Code::F10),
+    (0x44, Code::F11),
+    (0x45, Code::F12),
+    (0x46, Code::PrintScreen),
+    (0x47, Code::ScrollLock),
+    (0x48, Code::Pause),
+    (0x49, Code::Insert),
+    (0x4A, Code::Home),
+    (0x4B, Code::PageUp),
+    (0x4C, Code::Delete),
+    (0x4D, Code::End),
+    (0x4E, Code::PageDown),
+    (0x4F, Code::ArrowRight),
+    (0x50, Code::ArrowLeft),
+    (0x51, Code::ArrowDown),
+    (0x52, Code::ArrowUp),
+    (0x53, Code::NumLock),
+    (0x54, Code::NumpadDivide),
+    (0x55, Code::NumpadMultiply),
+    (0x56, Code::NumpadSubtract),
+    (0x57, Code::NumpadAdd),
+    (0x58, Code::NumpadEnter),
+    (0x59, Code::Numpad1),
+    (0x5A, Code::Numpad2),
+    (0x5B, Code::Numpad3),
+    (0x5C, Code::Numpad4),
+    (0x5D, Code::Numpad5),
+    (0x5E, Code::Numpad6),
+    (0x5F, Code::Numpad7),
+    (0x60, Code::Numpad8),
+    (0x61, Code::Numpad9),
+    (0x62, Code::Numpad0),
+    (0x63, Code::NumpadDecimal),
+    (0x64, Code::IntlBackslash),
+    (0x65, Code::ContextMenu),
+    (0x66, Code::Power),
+    (0x67, Code::NumpadEqual),
+    (0x68, Code::F13),
+    (0x69, Code::F14),
+    (0x6A, Code::F15),
+    (0x6B, Code::F16),
+    (0x6C, Code::F17),
+    (0x6D, Code::F18),
+    (0x6E, Code::F19),
+    (0x6F, Code::F20),
+    (0x70, Code::F21),
+    (0x71, Code::F22),
+    (0x72, Code::F23),
+    (0x73, Code::F24),
+    (0x74, Code::Open),
+    (0x75, Code::Help),
+    (0x77, Code::Select),
+    (0x79, Code::Again),
+    (0x7A, Code::Undo),
+    (0x7B, Code::Cut),
+    (0x7C, Code::Copy),
+    (0x7D, Code::Paste),
+    (0x7E, Code::Find),
+    (0x7F, Code::AudioVolumeMute),
+    (0x80, Code::AudioVolumeUp),
+    (0x81, Code::AudioVolumeDown),
+    (0x85, Code::NumpadComma),
+    (0x87, Code::IntlRo),
+    (0x88, Code::KanaMode),
+    (0x89, Code::IntlYen),
+    (0x8A, Code::Convert),
+    (0x8B, Code::NonConvert),
+    (0x90, Code::Lang1),
+    (0x91, Code::Lang2),
+    (0x92, Code::Lang3),
+    (0x93, Code::Lang4),
+    (0x94, Code::Lang5),
+    (0x9B, Code::Abort),
+    (0xA3, Code::Props),
+    (0xB6, Code::NumpadParenLeft),
+    (0xB7, Code::NumpadParenRight),
+    (0xBB, Code::NumpadBackspace),
+    (0xD0, Code::NumpadMemoryStore),
+    (0xD1, Code::NumpadMemoryRecall),
+    (0xD2, Code::NumpadMemoryClear),
+    (0xD3, Code::NumpadMemoryAdd),
+    (0xD4, Code::NumpadMemorySubtract),
+    (0xD8, Code::NumpadClear),
+    (0xD9, Code::NumpadClearEntry),
+    (0xE0, Code::ControlLeft),
+    (0xE1, Code::ShiftLeft),
+    (0xE2, Code::AltLeft),
+    (0xE3, Code::MetaLeft),
+    (0xE4, Code::ControlRight),
+    (0xE5, Code::ShiftRight),
+    (0xE6, Code::AltRight),
+    (0xE7, Code::MetaRight),
+];
+
+/// USB HID usage IDs of the system controls on the generic desktop page (0x01).
+const HID_GENERIC_DESKTOP: Table = &[
+    (0x81, Code::Power),
+    (0x82, Code::Sleep),
+    (0x83, Code::WakeUp),
+];
+
+/// USB HID usage IDs of the consumer page (0x0C).
+const HID_CONSUMER: Table = &[
+    (0x006F, Code::BrightnessUp),
+    (0x0070, Code::BrightnessDown),
+    (0x00B0, Code::MediaPlay),
+    (0x00B1, Code::MediaPause),
+    (0x00B2, Code::MediaRecord),
+    (0x00B3, Code::MediaFastForward),
+    (0x00B4, Code::MediaRewind),
+    (0x00B5, Code::MediaTrackNext),
+    (0x00B6, Code::MediaTrackPrevious),
+    (0x00B7, Code::MediaStop),
+    (0x00B8, Code::Eject),
+    (0x00CD, Code::MediaPlayPause),
+    (0x00E2, Code::AudioVolumeMute),
+    (0x00E9, Code::AudioVolumeUp),
+    (0x00EA, Code::AudioVolumeDown),
+    (0x0183, Code::MediaSelect),
+    (0x018A, Code::LaunchMail),
+    (0x0192, Code::LaunchApp2),
+    (0x0194, Code::LaunchApp1),
+    (0x019F, Code::LaunchControlPanel),
+    (0x01B1, Code::LaunchScreenSaver),
+    (0x0221, Code::BrowserSearch),
+    (0x0223, Code::BrowserHome),
+    (0x0224, Code::BrowserBack),
+    (0x0225, Code::BrowserForward),
+    (0x0226, Code::BrowserStop),
+    (0x0227, Code::BrowserRefresh),
+    (0x022A, Code::BrowserFavorites),
+    (0x0289, Code::MailReply),
+    (0x028B, Code::MailForward),
+    (0x028C, Code::MailSend),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::table::assert_valid;
+
+    #[test]
+    fn test_tables() {
+        assert_valid(EVDEV);
+        assert_valid(HID_KEYBOARD);
+        assert_valid(LEGACY_KEYCODE);
+        assert_eq!(Code::from_evdev(0), Code::Unidentified);
+        assert_eq!(Code::from_hid_usage(0xFFFF), Code::Unidentified);
+        assert_eq!(Code::Unidentified.to_evdev(), None);
+        assert_eq!(Code::from_legacy_keycode(16), Code::Unidentified);
+        assert_eq!(Code::from_legacy_keycode(91), Code::MetaLeft);
+        assert_eq!(Code::from_legacy_keycode(92), Code::MetaRight);
+        assert_eq!(Code::from_legacy_keycode(93), Code::ContextMenu);
+    }
+
+    #[test]
+    fn test_exhaustive() {
+        let tables = [
+            EVDEV,
+            HID_KEYBOARD,
+            HID_GENERIC_DESKTOP,
+            HID_CONSUMER,
+            LEGACY_KEYCODE,
+        ];
+        for table in &tables {
+            let last = table.last().unwrap().0;
+            for value in 0..=last + 1 {
+                let expected = table
+                    .iter()
+                    .find(|(v, _)| *v == value)
+                    .map_or(Code::Unidentified, |(_, code)| *code);
+                assert_eq!(lookup(table, value), expected);
+            }
+            for (value, code) in table.iter() {
+                assert_eq!(reverse_lookup(table, *code), Some(*value));
+            }
+        }
+    }
+
+    #[test]
+    fn test_hid_pages() {
+        assert_valid(HID_GENERIC_DESKTOP);
+        assert_valid(HID_CONSUMER);
+        assert_eq!(Code::KeyA.to_hid_usage_full(), Some((0x07, 0x04)));
+        assert_eq!(Code::Power.to_hid_usage_full(), Some((0x01, 0x81)));
+        assert_eq!(Code::from_hid_usage_full(0x07, 0x66), Code::Power);
+        assert_eq!(Code::AudioVolumeUp.to_hid_usage_full(), Some((0x0C, 0xE9)));
+        assert_eq!(Code::from_hid_usage_full(0x07, 0x80), Code::AudioVolumeUp);
+        assert_eq!(Code::from_hid_usage_full(0x09, 0x01), Code::Unidentified);
+        assert_eq!(Code::Unidentified.to_hid_usage_full(), None);
+    }
+}
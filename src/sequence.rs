@@ -0,0 +1,211 @@
+use alloc::vec::Vec;
+
+use crate::{Key, KeyState, KeyboardEvent, Modifiers};
+
+/// Result of feeding a key event to a [`SequenceMatcher`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum SequenceResult<T> {
+    /// A complete sequence was typed.
+    ///
+    /// The matcher is reset and waits for the next sequence.
+    Matched(T),
+
+    /// The keys typed so far are a prefix of at least one sequence.
+    Pending,
+
+    /// No sequence starts with the keys typed.
+    ///
+    /// The matcher is reset and the event should be handled otherwise.
+    NoMatch,
+}
+
+/// Match multi-key sequences like Emacs-style chords.
+///
+/// A sequence is a list of shortcuts which are typed one after another,
+/// like Control&nbsp;+&nbsp;X followed by Control&nbsp;+&nbsp;S.
+/// Keys and modifiers are compared like in [`ShortcutMatcher`](crate::ShortcutMatcher):
+/// only shift, control, alt and meta are taken into account and
+/// ASCII letters are compared ignoring case.
+///
+/// Key releases and presses of modifier keys do not advance the sequence.
+///
+/// ```rust
+/// # use gluten_keyboard::{Key, KeyboardEvent, Modifiers, SequenceMatcher, SequenceResult};
+/// let mut matcher = SequenceMatcher::new();
+/// matcher.bind(
+///     &[(Modifiers::CONTROL, Key::Character("x")), (Modifiers::CONTROL, Key::Character("s"))],
+///     "save",
+/// );
+/// let event = |c| KeyboardEvent {
+///     key: Key::Character(c),
+///     modifiers: Modifiers::CONTROL,
+///     ..Default::default()
+/// };
+/// assert_eq!(matcher.feed(&event("x")), SequenceResult::Pending);
+/// assert_eq!(matcher.feed(&event("s")), SequenceResult::Matched(&"save"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SequenceMatcher<'a, T> {
+    bindings: Vec<(Vec<(Modifiers, Key<'a>)>, T)>,
+    prefix: Vec<(Modifiers, Key<'a>)>,
+}
+
+impl<'a, T> SequenceMatcher<'a, T> {
+    /// Create a matcher without any sequences.
+    pub fn new() -> SequenceMatcher<'a, T> {
+        SequenceMatcher {
+            bindings: Vec::new(),
+            prefix: Vec::new(),
+        }
+    }
+
+    /// Add a sequence and the value returned when it is matched.
+    ///
+    /// Empty sequences are ignored. If a sequence is a prefix of
+    /// another one the shorter sequence wins.
+    pub fn bind(&mut self, sequence: &[(Modifiers, Key<'a>)], value: T) {
+        if sequence.is_empty() {
+            return;
+        }
+        let sequence = sequence
+            .iter()
+            .map(|(modifiers, key)| (normalize(*modifiers), *key))
+            .collect();
+        self.bindings.push((sequence, value));
+    }
+
+    /// Feed a key event into the matcher.
+    pub fn feed(&mut self, event: &KeyboardEvent) -> SequenceResult<&T> {
+        if event.state == KeyState::Up || event.key.modifier_flag().is_some() {
+            return if self.prefix.is_empty() {
+                SequenceResult::NoMatch
+            } else {
+                SequenceResult::Pending
+            };
+        }
+        let modifiers = normalize(event.modifiers);
+        let depth = self.prefix.len();
+        let prefix = &self.prefix;
+        let next = self
+            .bindings
+            .iter()
+            .filter(|(sequence, _)| starts_with(sequence, prefix))
+            .filter_map(|(sequence, _)| sequence.get(depth))
+            .find(|(m, k)| *m == modifiers && keys_match(k, &event.key))
+            .copied();
+        let next = match next {
+            Some(next) => next,
+            None => {
+                self.prefix.clear();
+                return SequenceResult::NoMatch;
+            }
+        };
+        self.prefix.push(next);
+        let prefix = &self.prefix;
+        match self
+            .bindings
+            .iter()
+            .find(|(sequence, _)| sequence.len() == prefix.len() && starts_with(sequence, prefix))
+        {
+            Some((_, value)) => {
+                self.prefix.clear();
+                SequenceResult::Matched(value)
+            }
+            None => SequenceResult::Pending,
+        }
+    }
+
+    /// Get the keys typed so far of a sequence in progress.
+    pub fn current_prefix(&self) -> &[(Modifiers, Key<'a>)] {
+        &self.prefix
+    }
+
+    /// Get the keys that continue the sequence in progress.
+    ///
+    /// Only sequences starting with the current prefix are considered.
+    /// Each possible next key is listed once, in the order the
+    /// sequences were bound. Useful to show which keys are valid next
+    /// after a partial chord.
+    pub fn suggestions(&self) -> Vec<(Modifiers, Key<'a>)> {
+        let depth = self.prefix.len();
+        let mut suggestions: Vec<(Modifiers, Key<'a>)> = Vec::new();
+        for (sequence, _) in &self.bindings {
+            if !starts_with(sequence, &self.prefix) {
+                continue;
+            }
+            if let Some(next) = sequence.get(depth) {
+                if !suggestions.contains(next) {
+                    suggestions.push(*next);
+                }
+            }
+        }
+        suggestions
+    }
+
+    /// Abort the sequence in progress.
+    pub fn reset(&mut self) {
+        self.prefix.clear();
+    }
+}
+
+impl<T> Default for SequenceMatcher<'_, T> {
+    fn default() -> Self {
+        SequenceMatcher::new()
+    }
+}
+
+fn normalize(modifiers: Modifiers) -> Modifiers {
+    modifiers & (Modifiers::SHIFT | Modifiers::CONTROL | Modifiers::ALT | Modifiers::META)
+}
+
+fn starts_with(sequence: &[(Modifiers, Key)], prefix: &[(Modifiers, Key)]) -> bool {
+    sequence.len() >= prefix.len()
+        && sequence
+            .iter()
+            .zip(prefix)
+            .all(|((m1, k1), (m2, k2))| m1 == m2 && keys_match(k1, k2))
+}
+
+fn keys_match(a: &Key, b: &Key) -> bool {
+    match (a, b) {
+        (Key::Character(a), Key::Character(b)) => a.eq_ignore_ascii_case(b),
+        _ => a == b,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ctrl(c: &'static str) -> (Modifiers, Key<'static>) {
+        (Modifiers::CONTROL, Key::Character(c))
+    }
+
+    fn event(c: &'static str) -> KeyboardEvent<'static> {
+        KeyboardEvent {
+            key: Key::Character(c),
+            modifiers: Modifiers::CONTROL,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_suggestions() {
+        let mut matcher = SequenceMatcher::new();
+        matcher.bind(&[ctrl("x"), ctrl("s")], 1);
+        matcher.bind(&[ctrl("x"), ctrl("f")], 2);
+        matcher.bind(&[ctrl("g")], 3);
+        assert_eq!(matcher.suggestions(), [ctrl("x"), ctrl("g")]);
+
+        assert_eq!(matcher.feed(&event("X")), SequenceResult::Pending);
+        assert_eq!(matcher.current_prefix(), [ctrl("x")]);
+        assert_eq!(matcher.suggestions(), [ctrl("s"), ctrl("f")]);
+
+        assert_eq!(matcher.feed(&event("f")), SequenceResult::Matched(&2));
+        assert!(matcher.current_prefix().is_empty());
+
+        assert_eq!(matcher.feed(&event("x")), SequenceResult::Pending);
+        assert_eq!(matcher.feed(&event("g")), SequenceResult::NoMatch);
+        assert!(matcher.current_prefix().is_empty());
+    }
+}
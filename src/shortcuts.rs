@@ -1,4 +1,7 @@
-use crate::{Key, KeyState, KeyboardEvent, Modifiers};
+use core::borrow::Borrow;
+
+use crate::modifiers::LOCKS;
+use crate::{Code, Key, KeyState, KeyboardEvent, Modifiers};
 
 /// Match keyboard shortcuts and excute actions.
 ///
@@ -15,48 +18,85 @@ use crate::{Key, KeyState, KeyboardEvent, Modifiers};
 /// the shift, control, alt and meta modifiers into account.
 /// If other modifiers beside those expected are found
 /// the shortcut is not matched.
-pub struct ShortcutMatcher<'a, T> {
+///
+/// Lock states like CapsLock or NumLock are not held modifiers
+/// and are ignored, so Control + S matches with CapsLock on.
+/// Use [`shortcut_with_locks`](Self::shortcut_with_locks) for the
+/// rare shortcut that depends on a lock state.
+pub struct ShortcutMatcher<'a, T, P = NoFallback> {
     state: KeyState,
     key: Key<'a>,
+    code: Code,
     modifiers: Modifiers,
+    locks: Modifiers,
     matched: bool,
     value: Option<T>,
+    fallback: P,
 }
 
 impl<'a, T> ShortcutMatcher<'a, T> {
     /// Create a new shortcut matcher.
-    pub fn new(state: KeyState, key: Key<'a>, mut modifiers: Modifiers) -> ShortcutMatcher<T> {
-        modifiers &= Modifiers::SHIFT | Modifiers::CONTROL | Modifiers::ALT | Modifiers::META;
+    ///
+    /// The physical key is unknown, so [`match_key_or_code`](Self::match_key_or_code)
+    /// only matches on the logical key.
+    pub fn new(state: KeyState, key: Key<'a>, modifiers: Modifiers) -> ShortcutMatcher<'a, T> {
         ShortcutMatcher {
             state,
             key,
-            modifiers,
+            code: Code::Unidentified,
+            modifiers: modifiers
+                & (Modifiers::SHIFT | Modifiers::CONTROL | Modifiers::ALT | Modifiers::META),
+            locks: modifiers & LOCKS,
             matched: false,
             value: None,
+            fallback: NoFallback,
         }
     }
 
     /// Create a new matcher from an event.
     ///
-    /// Only state, key and modifiers are stored. The other attributes are discarded.
-    pub fn from_event(key_event: KeyboardEvent) -> ShortcutMatcher<T> {
-        ShortcutMatcher::new(key_event.state, key_event.key, key_event.modifiers)
+    /// The event can be passed by value or by reference.
+    /// Only state, key, code and modifiers are stored. The other attributes are discarded.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::{Key, KeyboardEvent, Modifiers, ShortcutMatcher};
+    /// let events = [KeyboardEvent {
+    ///     key: Key::Character("s"),
+    ///     modifiers: Modifiers::CONTROL,
+    ///     ..Default::default()
+    /// }];
+    /// for event in &events {
+    ///     ShortcutMatcher::from_event(event)
+    ///         .shortcut(Modifiers::CONTROL, 's', || println!("save"))
+    ///         .otherwise(|| println!("forward"));
+    /// }
+    /// ```
+    pub fn from_event<E>(key_event: E) -> ShortcutMatcher<'a, T>
+    where
+        E: Borrow<KeyboardEvent<'a>>,
+    {
+        let key_event = key_event.borrow();
+        let mut matcher = ShortcutMatcher::new(key_event.state, key_event.key, key_event.modifiers);
+        matcher.code = key_event.code;
+        matcher
     }
+}
 
+impl<'a, T, P: Fallback<T>> ShortcutMatcher<'a, T, P> {
     /// Test a keyboard shortcut.
     ///
     /// If the modifiers are active and the key is pressed
     /// execute the provided function.
     ///
     /// ```rust
-    /// # use keyboard_types::{Key, KeyboardEvent, Modifiers, ShortcutMatcher};
+    /// # use gluten_keyboard::{Key, KeyboardEvent, Modifiers, ShortcutMatcher};
     /// # fn do_something() {}
     /// # fn forward_event() {}
     /// # let event = KeyboardEvent {
-    /// #     state: keyboard_types::KeyState::Down,
+    /// #     state: gluten_keyboard::KeyState::Down,
     /// #     key: Key::Enter,
-    /// #     code: keyboard_types::Code::Enter,
-    /// #     location: keyboard_types::Location::Standard,
+    /// #     code: gluten_keyboard::Code::Enter,
+    /// #     location: gluten_keyboard::Location::Standard,
     /// #     modifiers: Modifiers::empty(),
     /// #     repeat: false,
     /// #     is_composing: false,
@@ -76,7 +116,7 @@ impl<'a, T> ShortcutMatcher<'a, T> {
     /// // If none of the previous shortcuts matched forward the event.
     /// .otherwise(forward_event);
     /// ```
-    pub fn shortcut<K, F>(mut self, modifiers: Modifiers, key: K, f: F) -> ShortcutMatcher<'a, T>
+    pub fn shortcut<K, F>(mut self, modifiers: Modifiers, key: K, f: F) -> ShortcutMatcher<'a, T, P>
     where
         K: MatchKey,
         F: (FnOnce() -> T),
@@ -93,6 +133,36 @@ impl<'a, T> ShortcutMatcher<'a, T> {
         self
     }
 
+    /// Test a keyboard shortcut which also depends on lock states.
+    ///
+    /// Behaves like [`shortcut`](Self::shortcut) but the modifiers
+    /// may contain lock flags like `Modifiers::NUM_LOCK`. The shortcut
+    /// only matches if exactly these locks are active.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::{Key, KeyState, Modifiers, ShortcutMatcher};
+    /// let matched = ShortcutMatcher::new(KeyState::Down, Key::Home, Modifiers::NUM_LOCK)
+    ///     .shortcut_with_locks(Modifiers::empty(), Key::Home, || "home")
+    ///     .shortcut_with_locks(Modifiers::NUM_LOCK, Key::Home, || "home with NumLock")
+    ///     .otherwise(|| "none");
+    /// assert_eq!(matched, Some("home with NumLock"));
+    /// ```
+    pub fn shortcut_with_locks<K, F>(
+        self,
+        modifiers: Modifiers,
+        key: K,
+        f: F,
+    ) -> ShortcutMatcher<'a, T, P>
+    where
+        K: MatchKey,
+        F: (FnOnce() -> T),
+    {
+        if self.matched || modifiers & LOCKS != self.locks {
+            return self;
+        }
+        self.shortcut(modifiers - LOCKS, key, f)
+    }
+
     /// Only test a shortcut if the enabled flag is set.
     ///
     /// If the `enabled` flag is true behaves the same as
@@ -101,14 +171,14 @@ impl<'a, T> ShortcutMatcher<'a, T> {
     /// This is especially useful for platform specific shortcuts.
     ///
     /// ```rust
-    /// # use keyboard_types::{Key, KeyboardEvent, Modifiers, ShortcutMatcher};
+    /// # use gluten_keyboard::{Key, KeyboardEvent, Modifiers, ShortcutMatcher};
     /// # fn copy() {}
     /// # fn quit() {}
     /// # let event = KeyboardEvent {
-    /// #     state: keyboard_types::KeyState::Down,
+    /// #     state: gluten_keyboard::KeyState::Down,
     /// #     key: Key::Enter,
-    /// #     code: keyboard_types::Code::Enter,
-    /// #     location: keyboard_types::Location::Standard,
+    /// #     code: gluten_keyboard::Code::Enter,
+    /// #     location: gluten_keyboard::Location::Standard,
     /// #     modifiers: Modifiers::empty(),
     /// #     repeat: false,
     /// #     is_composing: false,
@@ -128,7 +198,7 @@ impl<'a, T> ShortcutMatcher<'a, T> {
         modifiers: Modifiers,
         key: K,
         f: F,
-    ) -> ShortcutMatcher<'a, T>
+    ) -> ShortcutMatcher<'a, T, P>
     where
         K: MatchKey,
         F: (FnOnce() -> T),
@@ -139,19 +209,153 @@ impl<'a, T> ShortcutMatcher<'a, T> {
         self.shortcut(modifiers, key, f)
     }
 
+    /// Test a keyboard shortcut by logical key with a fallback to the physical key.
+    ///
+    /// The shortcut matches if the modifiers are active and either
+    /// the key matches, or the key doesn't match but the code does.
+    /// This way `Control + /` works on layouts where the slash is on a
+    /// different key, and the binding still works on layouts like
+    /// Russian where the key at the `Code::Slash` position produces
+    /// another character.
+    ///
+    /// The logical key always takes precedence. A match by code is only
+    /// tentative: if a later shortcut matches the logical key that one
+    /// wins, no matter the order of the shortcuts. Otherwise the first
+    /// code match is executed by [`otherwise`](Self::otherwise), so
+    /// always end the chain with it when using this method.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::{Code, Key, KeyboardEvent, Modifiers, ShortcutMatcher};
+    /// let event = KeyboardEvent {
+    ///     key: Key::Character("с"),
+    ///     code: Code::KeyC,
+    ///     modifiers: Modifiers::CONTROL,
+    ///     ..Default::default()
+    /// };
+    /// let copy = ShortcutMatcher::from_event(event)
+    ///     .match_key_or_code(Modifiers::CONTROL, 'c', Code::KeyC, || true)
+    ///     .otherwise(|| false);
+    /// assert_eq!(copy, Some(true));
+    /// ```
+    pub fn match_key_or_code<K, F>(
+        self,
+        modifiers: Modifiers,
+        key: K,
+        code: Code,
+        f: F,
+    ) -> ShortcutMatcher<'a, T, CodeFallback<P, F>>
+    where
+        K: MatchKey,
+        F: (FnOnce() -> T),
+    {
+        let ShortcutMatcher {
+            state,
+            key: pressed,
+            code: pressed_code,
+            modifiers: pressed_modifiers,
+            locks,
+            mut matched,
+            mut value,
+            fallback,
+        } = self;
+        let active = !matched && modifiers == pressed_modifiers;
+        let mut f = Some(f);
+        if active && key.match_key(&pressed) {
+            if state == KeyState::Down {
+                value = f.take().map(|f| f());
+            }
+            matched = true;
+        }
+        let by_code = active
+            && !matched
+            && !fallback.is_pending()
+            && pressed_code != Code::Unidentified
+            && pressed_code == code;
+        ShortcutMatcher {
+            state,
+            key: pressed,
+            code: pressed_code,
+            modifiers: pressed_modifiers,
+            locks,
+            matched,
+            value,
+            fallback: CodeFallback {
+                earlier: fallback,
+                f: f.filter(|_| by_code),
+            },
+        }
+    }
+
     /// Execute the function is no keyboard shortcut matched.
     ///
     /// Note that the passed function is exectued on both
     /// keydown and keyup unlike the shortcuts which only
     /// run on keydown.
+    ///
+    /// If only the code of a [`match_key_or_code`](Self::match_key_or_code)
+    /// shortcut matched, that shortcut is executed instead.
     pub fn otherwise<F>(self, f: F) -> Option<T>
     where
         F: (FnOnce() -> T),
     {
-        if !self.matched {
+        if self.matched {
+            self.value
+        } else if self.fallback.is_pending() {
+            if self.state == KeyState::Down {
+                self.fallback.run()
+            } else {
+                None
+            }
+        } else {
             Some(f())
+        }
+    }
+}
+
+/// A shortcut matched by code, waiting for [`ShortcutMatcher::otherwise`].
+pub trait Fallback<T> {
+    /// Check if a shortcut matched by code.
+    fn is_pending(&self) -> bool;
+
+    /// Execute the shortcut matched by code, if any.
+    fn run(self) -> Option<T>;
+}
+
+/// No shortcut matched by code is possible.
+pub struct NoFallback;
+
+impl<T> Fallback<T> for NoFallback {
+    fn is_pending(&self) -> bool {
+        false
+    }
+
+    fn run(self) -> Option<T> {
+        None
+    }
+}
+
+/// The shortcuts of [`ShortcutMatcher::match_key_or_code`] calls.
+///
+/// Only the first shortcut matched by code is kept.
+pub struct CodeFallback<P, F> {
+    earlier: P,
+    f: Option<F>,
+}
+
+impl<T, P, F> Fallback<T> for CodeFallback<P, F>
+where
+    P: Fallback<T>,
+    F: FnOnce() -> T,
+{
+    fn is_pending(&self) -> bool {
+        self.earlier.is_pending() || self.f.is_some()
+    }
+
+    fn run(self) -> Option<T> {
+        if self.earlier.is_pending() {
+            self.earlier.run()
         } else {
-            self.value
+            self.f.map(|f| f())
         }
     }
 }
@@ -177,3 +381,88 @@ impl MatchKey for char {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn event(key: Key<'_>, code: Code) -> KeyboardEvent<'_> {
+        KeyboardEvent {
+            key,
+            code,
+            modifiers: Modifiers::CONTROL,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_match_key_or_code() {
+        let slash = |event: &KeyboardEvent| {
+            ShortcutMatcher::from_event(event)
+                .match_key_or_code(Modifiers::CONTROL, '/', Code::Slash, || "comment")
+                .otherwise(|| "none")
+        };
+        // US layout.
+        assert_eq!(
+            slash(&event(Key::Character("/"), Code::Slash)),
+            Some("comment")
+        );
+        // German layout, the slash position produces a minus and the
+        // slash is on the 7 key.
+        assert_eq!(
+            slash(&event(Key::Character("-"), Code::Slash)),
+            Some("comment")
+        );
+        assert_eq!(
+            slash(&event(Key::Character("/"), Code::Digit7)),
+            Some("comment")
+        );
+        assert_eq!(
+            slash(&event(Key::Character("-"), Code::Minus)),
+            Some("none")
+        );
+        let mut unknown = event(Key::Character("-"), Code::Unidentified);
+        assert_eq!(slash(&unknown), Some("none"));
+        unknown.modifiers = Modifiers::empty();
+        unknown.code = Code::Slash;
+        assert_eq!(slash(&unknown), Some("none"));
+    }
+
+    #[test]
+    fn test_order() {
+        let shortcuts = |event: &KeyboardEvent| {
+            ShortcutMatcher::from_event(event)
+                .match_key_or_code(Modifiers::CONTROL, '/', Code::Slash, || "comment")
+                .match_key_or_code(Modifiers::CONTROL, '-', Code::Minus, || "zoom out")
+                .otherwise(|| "none")
+        };
+        // On a German layout the minus key is at the slash position
+        // and the slash is on the 7 key.
+        assert_eq!(
+            shortcuts(&event(Key::Character("-"), Code::Slash)),
+            Some("zoom out")
+        );
+        assert_eq!(
+            shortcuts(&event(Key::Character("/"), Code::Digit7)),
+            Some("comment")
+        );
+        // On a Russian layout neither character is produced.
+        assert_eq!(
+            shortcuts(&event(Key::Character("."), Code::Slash)),
+            Some("comment")
+        );
+        assert_eq!(
+            shortcuts(&event(Key::Character("-"), Code::Minus)),
+            Some("zoom out")
+        );
+        let mut release = event(Key::Character("."), Code::Slash);
+        release.state = KeyState::Up;
+        assert_eq!(shortcuts(&release), None);
+
+        let exact_later = ShortcutMatcher::from_event(event(Key::Character("-"), Code::Slash))
+            .match_key_or_code(Modifiers::CONTROL, '/', Code::Slash, || "comment")
+            .shortcut(Modifiers::CONTROL, '-', || "zoom out")
+            .otherwise(|| "none");
+        assert_eq!(exact_later, Some("zoom out"));
+    }
+}
@@ -0,0 +1,126 @@
+//! Serialize a [`KeyboardEvent`] without its default fields.
+//!
+//! By default all fields of an event are serialized. Use this module
+//! with `#[serde(with = "...")]` to omit the location, modifiers,
+//! repeat and composing flags if they have their default value, which
+//! makes logs of many events considerably smaller.
+//!
+//! ```rust
+//! # use gluten_keyboard::{Key, KeyboardEvent};
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Entry<'a> {
+//!     time: u64,
+//!     #[serde(borrow, with = "gluten_keyboard::skip_defaults")]
+//!     event: KeyboardEvent<'a>,
+//! }
+//! ```
+//!
+//! Only use it with self-describing formats like JSON. Formats with a
+//! fixed field order like bincode can't tell which fields were skipped.
+//! Missing fields are filled with their defaults when deserializing,
+//! both with this module and with the default representation.
+
+use serde::de::Deserializer;
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+use crate::{Code, Key, KeyState, KeyboardEvent, Location, Modifiers};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "KeyboardEvent", bound(deserialize = "'de: 'a"))]
+struct Compact<'a> {
+    state: KeyState,
+    key: Key<'a>,
+    code: Code,
+    #[serde(default, skip_serializing_if = "Location::is_standard")]
+    location: Location,
+    #[serde(default, skip_serializing_if = "Modifiers::is_empty")]
+    modifiers: Modifiers,
+    #[serde(default, skip_serializing_if = "is_false")]
+    repeat: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    is_composing: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+/// Serialize the event without the fields having default values.
+pub fn serialize<S>(event: &KeyboardEvent<'_>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    Compact {
+        state: event.state,
+        key: event.key,
+        code: event.code,
+        location: event.location,
+        modifiers: event.modifiers,
+        repeat: event.repeat,
+        is_composing: event.is_composing,
+    }
+    .serialize(serializer)
+}
+
+/// Deserialize the event, filling in missing fields with their defaults.
+pub fn deserialize<'de: 'a, 'a, D>(deserializer: D) -> Result<KeyboardEvent<'a>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let event = Compact::deserialize(deserializer)?;
+    Ok(KeyboardEvent {
+        state: event.state,
+        key: event.key,
+        code: event.code,
+        location: event.location,
+        modifiers: event.modifiers,
+        repeat: event.repeat,
+        is_composing: event.is_composing,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Entry<'a> {
+        #[serde(borrow, with = "crate::skip_defaults")]
+        event: KeyboardEvent<'a>,
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let default = Entry {
+            event: KeyboardEvent::default(),
+        };
+        let json = serde_json::to_string(&default).unwrap();
+        assert_eq!(
+            json,
+            r#"{"event":{"state":"Down","key":{"Unidentified":null},"code":"Unidentified"}}"#
+        );
+        assert_eq!(serde_json::from_str::<Entry>(&json).unwrap(), default);
+        let event = serde_json::from_str::<KeyboardEvent>(
+            r#"{"state":"Down","key":"Enter","code":"Enter"}"#,
+        )
+        .unwrap();
+        assert_eq!(event.modifiers, Modifiers::empty());
+
+        let full = Entry {
+            event: KeyboardEvent {
+                state: KeyState::Up,
+                key: Key::Character("a"),
+                code: Code::KeyA,
+                location: Location::Numpad,
+                modifiers: Modifiers::SHIFT,
+                repeat: true,
+                is_composing: true,
+            },
+        };
+        let json = serde_json::to_string(&full).unwrap();
+        assert!(json.contains(r#""location":"Numpad""#));
+        assert!(json.contains(r#""is_composing":true"#));
+        assert_eq!(serde_json::from_str::<Entry>(&json).unwrap(), full);
+    }
+}
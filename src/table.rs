@@ -0,0 +1,46 @@
+//! Lookup in static conversion tables.
+//!
+//! Conversions from platform key codes are stored as slices of
+//! `(value, Code)` pairs sorted by value. This keeps the tables compact
+//! and makes adding an entry a one line change. Lookups use a binary
+//! search, `benches/lookup.rs` compares them with an equivalent `match`.
+
+use crate::Code;
+
+/// A conversion table sorted by the platform value.
+pub(crate) type Table = &'static [(u32, Code)];
+
+/// Find the code for a platform value.
+///
+/// Returns `Code::Unidentified` if the value is not in the table.
+pub(crate) fn lookup(table: Table, value: u32) -> Code {
+    match table.binary_search_by_key(&value, |(v, _)| *v) {
+        Ok(index) => table[index].1,
+        Err(_) => Code::Unidentified,
+    }
+}
+
+/// Find the platform value for a code.
+pub(crate) fn reverse_lookup(table: Table, code: Code) -> Option<u32> {
+    if code == Code::Unidentified {
+        return None;
+    }
+    table.iter().find(|(_, c)| *c == code).map(|(v, _)| *v)
+}
+
+/// Check that a table is usable for lookups in both directions.
+#[cfg(test)]
+pub(crate) fn assert_valid(table: Table) {
+    for pair in table.windows(2) {
+        assert!(
+            pair[0].0 < pair[1].0,
+            "table not sorted at {:#x}",
+            pair[1].0
+        );
+    }
+    for (value, code) in table {
+        assert_ne!(*code, Code::Unidentified);
+        assert_eq!(lookup(table, *value), *code);
+        assert_eq!(reverse_lookup(table, *code), Some(*value), "{:?}", code);
+    }
+}
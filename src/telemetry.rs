@@ -0,0 +1,1134 @@
+//! Stable names of keys and codes for logging and analytics.
+//!
+//! Also provides lossy labels of keys for search.
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+use crate::{Code, Key};
+
+impl Key<'_> {
+    /// Get a stable identifier of the key for telemetry.
+    ///
+    /// Named keys use their specification name like `"Enter"`. To avoid
+    /// logging typed text all character keys are reported as `"<char>"`
+    /// and dead keys as `"<dead>"`.
+    ///
+    /// The identifiers are guaranteed not to change in future versions,
+    /// even if a variant or its specification name is renamed. They are
+    /// listed here instead of derived from the names used elsewhere in
+    /// the crate. New variants get new identifiers.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::Key;
+    /// assert_eq!(Key::Enter.telemetry_name(), "Enter");
+    /// assert_eq!(Key::Character("p").telemetry_name(), "<char>");
+    /// ```
+    pub fn telemetry_name(&self) -> &'static str {
+        match self {
+            Key::Character(_) => "<char>",
+            Key::Dead => "<dead>",
+            Key::Unidentified(_) => "Unidentified",
+            Key::Alt => "Alt",
+            Key::AltGraph => "AltGraph",
+            Key::CapsLock => "CapsLock",
+            Key::Control => "Control",
+            Key::Fn => "Fn",
+            Key::FnLock => "FnLock",
+            Key::Meta => "Meta",
+            Key::NumLock => "NumLock",
+            Key::ScrollLock => "ScrollLock",
+            Key::Shift => "Shift",
+            Key::Symbol => "Symbol",
+            Key::SymbolLock => "SymbolLock",
+            Key::Hyper => "Hyper",
+            Key::Super => "Super",
+            Key::Enter => "Enter",
+            Key::Tab => "Tab",
+            Key::ArrowDown => "ArrowDown",
+            Key::ArrowLeft => "ArrowLeft",
+            Key::ArrowRight => "ArrowRight",
+            Key::ArrowUp => "ArrowUp",
+            Key::End => "End",
+            Key::Home => "Home",
+            Key::PageDown => "PageDown",
+            Key::PageUp => "PageUp",
+            Key::Backspace => "Backspace",
+            Key::Clear => "Clear",
+            Key::Copy => "Copy",
+            Key::CrSel => "CrSel",
+            Key::Cut => "Cut",
+            Key::Delete => "Delete",
+            Key::EraseEof => "EraseEof",
+            Key::ExSel => "ExSel",
+            Key::Insert => "Insert",
+            Key::Paste => "Paste",
+            Key::Redo => "Redo",
+            Key::Undo => "Undo",
+            Key::Accept => "Accept",
+            Key::Again => "Again",
+            Key::Attn => "Attn",
+            Key::Cancel => "Cancel",
+            Key::ContextMenu => "ContextMenu",
+            Key::Escape => "Escape",
+            Key::Execute => "Execute",
+            Key::Find => "Find",
+            Key::Help => "Help",
+            Key::Pause => "Pause",
+            Key::Play => "Play",
+            Key::Props => "Props",
+            Key::Select => "Select",
+            Key::ZoomIn => "ZoomIn",
+            Key::ZoomOut => "ZoomOut",
+            Key::BrightnessDown => "BrightnessDown",
+            Key::BrightnessUp => "BrightnessUp",
+            Key::Eject => "Eject",
+            Key::LogOff => "LogOff",
+            Key::Power => "Power",
+            Key::PowerOff => "PowerOff",
+            Key::PrintScreen => "PrintScreen",
+            Key::Hibernate => "Hibernate",
+            Key::Standby => "Standby",
+            Key::WakeUp => "WakeUp",
+            Key::AllCandidates => "AllCandidates",
+            Key::Alphanumeric => "Alphanumeric",
+            Key::CodeInput => "CodeInput",
+            Key::Compose => "Compose",
+            Key::Convert => "Convert",
+            Key::FinalMode => "FinalMode",
+            Key::GroupFirst => "GroupFirst",
+            Key::GroupLast => "GroupLast",
+            Key::GroupNext => "GroupNext",
+            Key::GroupPrevious => "GroupPrevious",
+            Key::ModeChange => "ModeChange",
+            Key::NextCandidate => "NextCandidate",
+            Key::NonConvert => "NonConvert",
+            Key::PreviousCandidate => "PreviousCandidate",
+            Key::Process => "Process",
+            Key::SingleCandidate => "SingleCandidate",
+            Key::HangulMode => "HangulMode",
+            Key::HanjaMode => "HanjaMode",
+            Key::JunjaMode => "JunjaMode",
+            Key::Eisu => "Eisu",
+            Key::Hankaku => "Hankaku",
+            Key::Hiragana => "Hiragana",
+            Key::HiraganaKatakana => "HiraganaKatakana",
+            Key::KanaMode => "KanaMode",
+            Key::KanjiMode => "KanjiMode",
+            Key::Katakana => "Katakana",
+            Key::Romaji => "Romaji",
+            Key::Zenkaku => "Zenkaku",
+            Key::ZenkakuHankaku => "ZenkakuHankaku",
+            Key::F1 => "F1",
+            Key::F2 => "F2",
+            Key::F3 => "F3",
+            Key::F4 => "F4",
+            Key::F5 => "F5",
+            Key::F6 => "F6",
+            Key::F7 => "F7",
+            Key::F8 => "F8",
+            Key::F9 => "F9",
+            Key::F10 => "F10",
+            Key::F11 => "F11",
+            Key::F12 => "F12",
+            Key::Soft1 => "Soft1",
+            Key::Soft2 => "Soft2",
+            Key::Soft3 => "Soft3",
+            Key::Soft4 => "Soft4",
+            Key::ChannelDown => "ChannelDown",
+            Key::ChannelUp => "ChannelUp",
+            Key::Close => "Close",
+            Key::MailForward => "MailForward",
+            Key::MailReply => "MailReply",
+            Key::MailSend => "MailSend",
+            Key::MediaClose => "MediaClose",
+            Key::MediaFastForward => "MediaFastForward",
+            Key::MediaPause => "MediaPause",
+            Key::MediaPlay => "MediaPlay",
+            Key::MediaPlayPause => "MediaPlayPause",
+            Key::MediaRecord => "MediaRecord",
+            Key::MediaRewind => "MediaRewind",
+            Key::MediaStop => "MediaStop",
+            Key::MediaTrackNext => "MediaTrackNext",
+            Key::MediaTrackPrevious => "MediaTrackPrevious",
+            Key::New => "New",
+            Key::Open => "Open",
+            Key::Print => "Print",
+            Key::Save => "Save",
+            Key::SpellCheck => "SpellCheck",
+            Key::Key11 => "Key11",
+            Key::Key12 => "Key12",
+            Key::AudioBalanceLeft => "AudioBalanceLeft",
+            Key::AudioBalanceRight => "AudioBalanceRight",
+            Key::AudioBassBoostDown => "AudioBassBoostDown",
+            Key::AudioBassBoostToggle => "AudioBassBoostToggle",
+            Key::AudioBassBoostUp => "AudioBassBoostUp",
+            Key::AudioFaderFront => "AudioFaderFront",
+            Key::AudioFaderRear => "AudioFaderRear",
+            Key::AudioSurroundModeNext => "AudioSurroundModeNext",
+            Key::AudioTrebleDown => "AudioTrebleDown",
+            Key::AudioTrebleUp => "AudioTrebleUp",
+            Key::AudioVolumeDown => "AudioVolumeDown",
+            Key::AudioVolumeUp => "AudioVolumeUp",
+            Key::AudioVolumeMute => "AudioVolumeMute",
+            Key::MicrophoneToggle => "MicrophoneToggle",
+            Key::MicrophoneVolumeDown => "MicrophoneVolumeDown",
+            Key::MicrophoneVolumeUp => "MicrophoneVolumeUp",
+            Key::MicrophoneVolumeMute => "MicrophoneVolumeMute",
+            Key::SpeechCorrectionList => "SpeechCorrectionList",
+            Key::SpeechInputToggle => "SpeechInputToggle",
+            Key::LaunchApplication1 => "LaunchApplication1",
+            Key::LaunchApplication2 => "LaunchApplication2",
+            Key::LaunchCalendar => "LaunchCalendar",
+            Key::LaunchContacts => "LaunchContacts",
+            Key::LaunchMail => "LaunchMail",
+            Key::LaunchMediaPlayer => "LaunchMediaPlayer",
+            Key::LaunchMusicPlayer => "LaunchMusicPlayer",
+            Key::LaunchPhone => "LaunchPhone",
+            Key::LaunchScreenSaver => "LaunchScreenSaver",
+            Key::LaunchSpreadsheet => "LaunchSpreadsheet",
+            Key::LaunchWebBrowser => "LaunchWebBrowser",
+            Key::LaunchWebCam => "LaunchWebCam",
+            Key::LaunchWordProcessor => "LaunchWordProcessor",
+            Key::BrowserBack => "BrowserBack",
+            Key::BrowserFavorites => "BrowserFavorites",
+            Key::BrowserForward => "BrowserForward",
+            Key::BrowserHome => "BrowserHome",
+            Key::BrowserRefresh => "BrowserRefresh",
+            Key::BrowserSearch => "BrowserSearch",
+            Key::BrowserStop => "BrowserStop",
+            Key::AppSwitch => "AppSwitch",
+            Key::Call => "Call",
+            Key::Camera => "Camera",
+            Key::CameraFocus => "CameraFocus",
+            Key::EndCall => "EndCall",
+            Key::GoBack => "GoBack",
+            Key::GoHome => "GoHome",
+            Key::HeadsetHook => "HeadsetHook",
+            Key::LastNumberRedial => "LastNumberRedial",
+            Key::Notification => "Notification",
+            Key::MannerMode => "MannerMode",
+            Key::VoiceDial => "VoiceDial",
+            Key::TV => "TV",
+            Key::TV3DMode => "TV3DMode",
+            Key::TVAntennaCable => "TVAntennaCable",
+            Key::TVAudioDescription => "TVAudioDescription",
+            Key::TVAudioDescriptionMixDown => "TVAudioDescriptionMixDown",
+            Key::TVAudioDescriptionMixUp => "TVAudioDescriptionMixUp",
+            Key::TVContentsMenu => "TVContentsMenu",
+            Key::TVDataService => "TVDataService",
+            Key::TVInput => "TVInput",
+            Key::TVInputComponent1 => "TVInputComponent1",
+            Key::TVInputComponent2 => "TVInputComponent2",
+            Key::TVInputComposite1 => "TVInputComposite1",
+            Key::TVInputComposite2 => "TVInputComposite2",
+            Key::TVInputHDMI1 => "TVInputHDMI1",
+            Key::TVInputHDMI2 => "TVInputHDMI2",
+            Key::TVInputHDMI3 => "TVInputHDMI3",
+            Key::TVInputHDMI4 => "TVInputHDMI4",
+            Key::TVInputVGA1 => "TVInputVGA1",
+            Key::TVMediaContext => "TVMediaContext",
+            Key::TVNetwork => "TVNetwork",
+            Key::TVNumberEntry => "TVNumberEntry",
+            Key::TVPower => "TVPower",
+            Key::TVRadioService => "TVRadioService",
+            Key::TVSatellite => "TVSatellite",
+            Key::TVSatelliteBS => "TVSatelliteBS",
+            Key::TVSatelliteCS => "TVSatelliteCS",
+            Key::TVSatelliteToggle => "TVSatelliteToggle",
+            Key::TVTerrestrialAnalog => "TVTerrestrialAnalog",
+            Key::TVTerrestrialDigital => "TVTerrestrialDigital",
+            Key::TVTimer => "TVTimer",
+            Key::AVRInput => "AVRInput",
+            Key::AVRPower => "AVRPower",
+            Key::ColorF0Red => "ColorF0Red",
+            Key::ColorF1Green => "ColorF1Green",
+            Key::ColorF2Yellow => "ColorF2Yellow",
+            Key::ColorF3Blue => "ColorF3Blue",
+            Key::ColorF4Grey => "ColorF4Grey",
+            Key::ColorF5Brown => "ColorF5Brown",
+            Key::ClosedCaptionToggle => "ClosedCaptionToggle",
+            Key::Dimmer => "Dimmer",
+            Key::DisplaySwap => "DisplaySwap",
+            Key::DVR => "DVR",
+            Key::Exit => "Exit",
+            Key::FavoriteClear0 => "FavoriteClear0",
+            Key::FavoriteClear1 => "FavoriteClear1",
+            Key::FavoriteClear2 => "FavoriteClear2",
+            Key::FavoriteClear3 => "FavoriteClear3",
+            Key::FavoriteRecall0 => "FavoriteRecall0",
+            Key::FavoriteRecall1 => "FavoriteRecall1",
+            Key::FavoriteRecall2 => "FavoriteRecall2",
+            Key::FavoriteRecall3 => "FavoriteRecall3",
+            Key::FavoriteStore0 => "FavoriteStore0",
+            Key::FavoriteStore1 => "FavoriteStore1",
+            Key::FavoriteStore2 => "FavoriteStore2",
+            Key::FavoriteStore3 => "FavoriteStore3",
+            Key::Guide => "Guide",
+            Key::GuideNextDay => "GuideNextDay",
+            Key::GuidePreviousDay => "GuidePreviousDay",
+            Key::Info => "Info",
+            Key::InstantReplay => "InstantReplay",
+            Key::Link => "Link",
+            Key::ListProgram => "ListProgram",
+            Key::LiveContent => "LiveContent",
+            Key::Lock => "Lock",
+            Key::MediaApps => "MediaApps",
+            Key::MediaAudioTrack => "MediaAudioTrack",
+            Key::MediaLast => "MediaLast",
+            Key::MediaSkipBackward => "MediaSkipBackward",
+            Key::MediaSkipForward => "MediaSkipForward",
+            Key::MediaStepBackward => "MediaStepBackward",
+            Key::MediaStepForward => "MediaStepForward",
+            Key::MediaTopMenu => "MediaTopMenu",
+            Key::NavigateIn => "NavigateIn",
+            Key::NavigateNext => "NavigateNext",
+            Key::NavigateOut => "NavigateOut",
+            Key::NavigatePrevious => "NavigatePrevious",
+            Key::NextFavoriteChannel => "NextFavoriteChannel",
+            Key::NextUserProfile => "NextUserProfile",
+            Key::OnDemand => "OnDemand",
+            Key::Pairing => "Pairing",
+            Key::PinPDown => "PinPDown",
+            Key::PinPMove => "PinPMove",
+            Key::PinPToggle => "PinPToggle",
+            Key::PinPUp => "PinPUp",
+            Key::PlaySpeedDown => "PlaySpeedDown",
+            Key::PlaySpeedReset => "PlaySpeedReset",
+            Key::PlaySpeedUp => "PlaySpeedUp",
+            Key::RandomToggle => "RandomToggle",
+            Key::RcLowBattery => "RcLowBattery",
+            Key::RecordSpeedNext => "RecordSpeedNext",
+            Key::RfBypass => "RfBypass",
+            Key::ScanChannelsToggle => "ScanChannelsToggle",
+            Key::ScreenModeNext => "ScreenModeNext",
+            Key::Settings => "Settings",
+            Key::SplitScreenToggle => "SplitScreenToggle",
+            Key::STBInput => "STBInput",
+            Key::STBPower => "STBPower",
+            Key::Subtitle => "Subtitle",
+            Key::Teletext => "Teletext",
+            Key::VideoModeNext => "VideoModeNext",
+            Key::Wink => "Wink",
+            Key::ZoomToggle => "ZoomToggle",
+            Key::F13 => "F13",
+            Key::F14 => "F14",
+            Key::F15 => "F15",
+            Key::F16 => "F16",
+            Key::F17 => "F17",
+            Key::F18 => "F18",
+            Key::F19 => "F19",
+            Key::F20 => "F20",
+            Key::F21 => "F21",
+            Key::F22 => "F22",
+            Key::F23 => "F23",
+            Key::F24 => "F24",
+        }
+    }
+
+    /// Get a short label of the key for searching through key bindings.
+    ///
+    /// Named keys use short names like `"Up"` for `ArrowUp` or `"Esc"`
+    /// for `Escape`, and the specification name otherwise. Combining
+    /// marks are removed from characters, with the `unicode` feature
+    /// also from precomposed characters like `é`. The space character
+    /// is labelled `"Space"`.
+    ///
+    /// The labels are lossy and may change between versions. Use them
+    /// only for search and display, never to store or parse keys.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::Key;
+    /// assert_eq!(Key::ArrowUp.search_label(), "Up");
+    /// assert_eq!(Key::Character("e\u{301}").search_label(), "e");
+    /// # #[cfg(feature = "unicode")]
+    /// assert_eq!(Key::Character("é").search_label(), "e");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn search_label(&self) -> String {
+        let label = match self {
+            Key::Character(" ") => "Space",
+            Key::Character(s) => {
+                let chars = s.chars().filter(|c| !('\u{300}'..='\u{36F}').contains(c));
+                #[cfg(feature = "unicode")]
+                let chars = chars.map(crate::unicode::base);
+                return chars.collect();
+            }
+            Key::ArrowUp => "Up",
+            Key::ArrowDown => "Down",
+            Key::ArrowLeft => "Left",
+            Key::ArrowRight => "Right",
+            Key::Control => "Ctrl",
+            Key::Escape => "Esc",
+            Key::Delete => "Del",
+            Key::Insert => "Ins",
+            Key::PageUp => "PgUp",
+            Key::PageDown => "PgDn",
+            key => return key.to_string(),
+        };
+        label.into()
+    }
+}
+
+impl Code {
+    /// Get a stable identifier of the code for telemetry.
+    ///
+    /// The identifiers are the specification names like `"KeyA"`
+    /// at the time the code was added. Like for
+    /// [`Key::telemetry_name`] they are guaranteed not to change.
+    pub fn telemetry_name(&self) -> &'static str {
+        match self {
+            Code::Backquote => "Backquote",
+            Code::Backslash => "Backslash",
+            Code::BracketLeft => "BracketLeft",
+            Code::BracketRight => "BracketRight",
+            Code::Comma => "Comma",
+            Code::Digit0 => "Digit0",
+            Code::Digit1 => "Digit1",
+            Code::Digit2 => "Digit2",
+            Code::Digit3 => "Digit3",
+            Code::Digit4 => "Digit4",
+            Code::Digit5 => "Digit5",
+            Code::Digit6 => "Digit6",
+            Code::Digit7 => "Digit7",
+            Code::Digit8 => "Digit8",
+            Code::Digit9 => "Digit9",
+            Code::Equal => "Equal",
+            Code::IntlBackslash => "IntlBackslash",
+            Code::IntlRo => "IntlRo",
+            Code::IntlYen => "IntlYen",
+            Code::KeyA => "KeyA",
+            Code::KeyB => "KeyB",
+            Code::KeyC => "KeyC",
+            Code::KeyD => "KeyD",
+            Code::KeyE => "KeyE",
+            Code::KeyF => "KeyF",
+            Code::KeyG => "KeyG",
+            Code::KeyH => "KeyH",
+            Code::KeyI => "KeyI",
+            Code::KeyJ => "KeyJ",
+            Code::KeyK => "KeyK",
+            Code::KeyL => "KeyL",
+            Code::KeyM => "KeyM",
+            Code::KeyN => "KeyN",
+            Code::KeyO => "KeyO",
+            Code::KeyP => "KeyP",
+            Code::KeyQ => "KeyQ",
+            Code::KeyR => "KeyR",
+            Code::KeyS => "KeyS",
+            Code::KeyT => "KeyT",
+            Code::KeyU => "KeyU",
+            Code::KeyV => "KeyV",
+            Code::KeyW => "KeyW",
+            Code::KeyX => "KeyX",
+            Code::KeyY => "KeyY",
+            Code::KeyZ => "KeyZ",
+            Code::Minus => "Minus",
+            Code::Period => "Period",
+            Code::Quote => "Quote",
+            Code::Semicolon => "Semicolon",
+            Code::Slash => "Slash",
+            Code::AltLeft => "AltLeft",
+            Code::AltRight => "AltRight",
+            Code::Backspace => "Backspace",
+            Code::CapsLock => "CapsLock",
+            Code::ContextMenu => "ContextMenu",
+            Code::ControlLeft => "ControlLeft",
+            Code::ControlRight => "ControlRight",
+            Code::Enter => "Enter",
+            Code::MetaLeft => "MetaLeft",
+            Code::MetaRight => "MetaRight",
+            Code::ShiftLeft => "ShiftLeft",
+            Code::ShiftRight => "ShiftRight",
+            Code::Space => "Space",
+            Code::Tab => "Tab",
+            Code::Convert => "Convert",
+            Code::KanaMode => "KanaMode",
+            Code::Lang1 => "Lang1",
+            Code::Lang2 => "Lang2",
+            Code::Lang3 => "Lang3",
+            Code::Lang4 => "Lang4",
+            Code::Lang5 => "Lang5",
+            Code::NonConvert => "NonConvert",
+            Code::Delete => "Delete",
+            Code::End => "End",
+            Code::Help => "Help",
+            Code::Home => "Home",
+            Code::Insert => "Insert",
+            Code::PageDown => "PageDown",
+            Code::PageUp => "PageUp",
+            Code::ArrowDown => "ArrowDown",
+            Code::ArrowLeft => "ArrowLeft",
+            Code::ArrowRight => "ArrowRight",
+            Code::ArrowUp => "ArrowUp",
+            Code::NumLock => "NumLock",
+            Code::Numpad0 => "Numpad0",
+            Code::Numpad1 => "Numpad1",
+            Code::Numpad2 => "Numpad2",
+            Code::Numpad3 => "Numpad3",
+            Code::Numpad4 => "Numpad4",
+            Code::Numpad5 => "Numpad5",
+            Code::Numpad6 => "Numpad6",
+            Code::Numpad7 => "Numpad7",
+            Code::Numpad8 => "Numpad8",
+            Code::Numpad9 => "Numpad9",
+            Code::NumpadAdd => "NumpadAdd",
+            Code::NumpadBackspace => "NumpadBackspace",
+            Code::NumpadClear => "NumpadClear",
+            Code::NumpadClearEntry => "NumpadClearEntry",
+            Code::NumpadComma => "NumpadComma",
+            Code::NumpadDecimal => "NumpadDecimal",
+            Code::NumpadDivide => "NumpadDivide",
+            Code::NumpadEnter => "NumpadEnter",
+            Code::NumpadEqual => "NumpadEqual",
+            Code::NumpadHash => "NumpadHash",
+            Code::NumpadMemoryAdd => "NumpadMemoryAdd",
+            Code::NumpadMemoryClear => "NumpadMemoryClear",
+            Code::NumpadMemoryRecall => "NumpadMemoryRecall",
+            Code::NumpadMemoryStore => "NumpadMemoryStore",
+            Code::NumpadMemorySubtract => "NumpadMemorySubtract",
+            Code::NumpadMultiply => "NumpadMultiply",
+            Code::NumpadParenLeft => "NumpadParenLeft",
+            Code::NumpadParenRight => "NumpadParenRight",
+            Code::NumpadStar => "NumpadStar",
+            Code::NumpadSubtract => "NumpadSubtract",
+            Code::Escape => "Escape",
+            Code::F1 => "F1",
+            Code::F2 => "F2",
+            Code::F3 => "F3",
+            Code::F4 => "F4",
+            Code::F5 => "F5",
+            Code::F6 => "F6",
+            Code::F7 => "F7",
+            Code::F8 => "F8",
+            Code::F9 => "F9",
+            Code::F10 => "F10",
+            Code::F11 => "F11",
+            Code::F12 => "F12",
+            Code::Fn => "Fn",
+            Code::FnLock => "FnLock",
+            Code::PrintScreen => "PrintScreen",
+            Code::ScrollLock => "ScrollLock",
+            Code::Pause => "Pause",
+            Code::BrowserBack => "BrowserBack",
+            Code::BrowserFavorites => "BrowserFavorites",
+            Code::BrowserForward => "BrowserForward",
+            Code::BrowserHome => "BrowserHome",
+            Code::BrowserRefresh => "BrowserRefresh",
+            Code::BrowserSearch => "BrowserSearch",
+            Code::BrowserStop => "BrowserStop",
+            Code::Eject => "Eject",
+            Code::LaunchApp1 => "LaunchApp1",
+            Code::LaunchApp2 => "LaunchApp2",
+            Code::LaunchMail => "LaunchMail",
+            Code::MediaPlayPause => "MediaPlayPause",
+            Code::MediaSelect => "MediaSelect",
+            Code::MediaStop => "MediaStop",
+            Code::MediaTrackNext => "MediaTrackNext",
+            Code::MediaTrackPrevious => "MediaTrackPrevious",
+            Code::Power => "Power",
+            Code::Sleep => "Sleep",
+            Code::AudioVolumeDown => "AudioVolumeDown",
+            Code::AudioVolumeMute => "AudioVolumeMute",
+            Code::AudioVolumeUp => "AudioVolumeUp",
+            Code::WakeUp => "WakeUp",
+            Code::Hyper => "Hyper",
+            Code::Super => "Super",
+            Code::Turbo => "Turbo",
+            Code::Abort => "Abort",
+            Code::Resume => "Resume",
+            Code::Suspend => "Suspend",
+            Code::Again => "Again",
+            Code::Copy => "Copy",
+            Code::Cut => "Cut",
+            Code::Find => "Find",
+            Code::Open => "Open",
+            Code::Paste => "Paste",
+            Code::Props => "Props",
+            Code::Select => "Select",
+            Code::Undo => "Undo",
+            Code::Hiragana => "Hiragana",
+            Code::Katakana => "Katakana",
+            Code::Unidentified => "Unidentified",
+            Code::F13 => "F13",
+            Code::F14 => "F14",
+            Code::F15 => "F15",
+            Code::F16 => "F16",
+            Code::F17 => "F17",
+            Code::F18 => "F18",
+            Code::F19 => "F19",
+            Code::F20 => "F20",
+            Code::F21 => "F21",
+            Code::F22 => "F22",
+            Code::F23 => "F23",
+            Code::F24 => "F24",
+            Code::BrightnessDown => "BrightnessDown",
+            Code::BrightnessUp => "BrightnessUp",
+            Code::DisplayToggleIntExt => "DisplayToggleIntExt",
+            Code::KeyboardLayoutSelect => "KeyboardLayoutSelect",
+            Code::LaunchAssistant => "LaunchAssistant",
+            Code::LaunchControlPanel => "LaunchControlPanel",
+            Code::LaunchScreenSaver => "LaunchScreenSaver",
+            Code::MailForward => "MailForward",
+            Code::MailReply => "MailReply",
+            Code::MailSend => "MailSend",
+            Code::MediaFastForward => "MediaFastForward",
+            Code::MediaPause => "MediaPause",
+            Code::MediaPlay => "MediaPlay",
+            Code::MediaRecord => "MediaRecord",
+            Code::MediaRewind => "MediaRewind",
+            Code::MicrophoneMuteToggle => "MicrophoneMuteToggle",
+            Code::PrivacyScreenToggle => "PrivacyScreenToggle",
+            Code::SelectTask => "SelectTask",
+            Code::ShowAllWindows => "ShowAllWindows",
+            Code::ZoomToggle => "ZoomToggle",
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_telemetry_names() {
+        assert_eq!(Key::Character("a").telemetry_name(), "<char>");
+        assert_eq!(Key::Dead.telemetry_name(), "<dead>");
+        assert_eq!(Key::Unidentified(Some(42)).telemetry_name(), "Unidentified");
+        assert_eq!(Key::ArrowLeft.telemetry_name(), "ArrowLeft");
+        assert_eq!(Key::AudioVolumeUp.telemetry_name(), "AudioVolumeUp");
+        assert_eq!(Key::F24.telemetry_name(), "F24");
+        assert_eq!(Code::KeyA.telemetry_name(), "KeyA");
+        assert_eq!(Code::MetaLeft.telemetry_name(), "MetaLeft");
+        assert_eq!(Code::NumpadEnter.telemetry_name(), "NumpadEnter");
+        assert_eq!(Code::Unidentified.telemetry_name(), "Unidentified");
+    }
+
+    #[test]
+    fn test_frozen_key_names() {
+        let names = [
+            (Key::Alt, "Alt"),
+            (Key::AltGraph, "AltGraph"),
+            (Key::CapsLock, "CapsLock"),
+            (Key::Control, "Control"),
+            (Key::Fn, "Fn"),
+            (Key::FnLock, "FnLock"),
+            (Key::Meta, "Meta"),
+            (Key::NumLock, "NumLock"),
+            (Key::ScrollLock, "ScrollLock"),
+            (Key::Shift, "Shift"),
+            (Key::Symbol, "Symbol"),
+            (Key::SymbolLock, "SymbolLock"),
+            (Key::Hyper, "Hyper"),
+            (Key::Super, "Super"),
+            (Key::Enter, "Enter"),
+            (Key::Tab, "Tab"),
+            (Key::ArrowDown, "ArrowDown"),
+            (Key::ArrowLeft, "ArrowLeft"),
+            (Key::ArrowRight, "ArrowRight"),
+            (Key::ArrowUp, "ArrowUp"),
+            (Key::End, "End"),
+            (Key::Home, "Home"),
+            (Key::PageDown, "PageDown"),
+            (Key::PageUp, "PageUp"),
+            (Key::Backspace, "Backspace"),
+            (Key::Clear, "Clear"),
+            (Key::Copy, "Copy"),
+            (Key::CrSel, "CrSel"),
+            (Key::Cut, "Cut"),
+            (Key::Delete, "Delete"),
+            (Key::EraseEof, "EraseEof"),
+            (Key::ExSel, "ExSel"),
+            (Key::Insert, "Insert"),
+            (Key::Paste, "Paste"),
+            (Key::Redo, "Redo"),
+            (Key::Undo, "Undo"),
+            (Key::Accept, "Accept"),
+            (Key::Again, "Again"),
+            (Key::Attn, "Attn"),
+            (Key::Cancel, "Cancel"),
+            (Key::ContextMenu, "ContextMenu"),
+            (Key::Escape, "Escape"),
+            (Key::Execute, "Execute"),
+            (Key::Find, "Find"),
+            (Key::Help, "Help"),
+            (Key::Pause, "Pause"),
+            (Key::Play, "Play"),
+            (Key::Props, "Props"),
+            (Key::Select, "Select"),
+            (Key::ZoomIn, "ZoomIn"),
+            (Key::ZoomOut, "ZoomOut"),
+            (Key::BrightnessDown, "BrightnessDown"),
+            (Key::BrightnessUp, "BrightnessUp"),
+            (Key::Eject, "Eject"),
+            (Key::LogOff, "LogOff"),
+            (Key::Power, "Power"),
+            (Key::PowerOff, "PowerOff"),
+            (Key::PrintScreen, "PrintScreen"),
+            (Key::Hibernate, "Hibernate"),
+            (Key::Standby, "Standby"),
+            (Key::WakeUp, "WakeUp"),
+            (Key::AllCandidates, "AllCandidates"),
+            (Key::Alphanumeric, "Alphanumeric"),
+            (Key::CodeInput, "CodeInput"),
+            (Key::Compose, "Compose"),
+            (Key::Convert, "Convert"),
+            (Key::FinalMode, "FinalMode"),
+            (Key::GroupFirst, "GroupFirst"),
+            (Key::GroupLast, "GroupLast"),
+            (Key::GroupNext, "GroupNext"),
+            (Key::GroupPrevious, "GroupPrevious"),
+            (Key::ModeChange, "ModeChange"),
+            (Key::NextCandidate, "NextCandidate"),
+            (Key::NonConvert, "NonConvert"),
+            (Key::PreviousCandidate, "PreviousCandidate"),
+            (Key::Process, "Process"),
+            (Key::SingleCandidate, "SingleCandidate"),
+            (Key::HangulMode, "HangulMode"),
+            (Key::HanjaMode, "HanjaMode"),
+            (Key::JunjaMode, "JunjaMode"),
+            (Key::Eisu, "Eisu"),
+            (Key::Hankaku, "Hankaku"),
+            (Key::Hiragana, "Hiragana"),
+            (Key::HiraganaKatakana, "HiraganaKatakana"),
+            (Key::KanaMode, "KanaMode"),
+            (Key::KanjiMode, "KanjiMode"),
+            (Key::Katakana, "Katakana"),
+            (Key::Romaji, "Romaji"),
+            (Key::Zenkaku, "Zenkaku"),
+            (Key::ZenkakuHankaku, "ZenkakuHankaku"),
+            (Key::F1, "F1"),
+            (Key::F2, "F2"),
+            (Key::F3, "F3"),
+            (Key::F4, "F4"),
+            (Key::F5, "F5"),
+            (Key::F6, "F6"),
+            (Key::F7, "F7"),
+            (Key::F8, "F8"),
+            (Key::F9, "F9"),
+            (Key::F10, "F10"),
+            (Key::F11, "F11"),
+            (Key::F12, "F12"),
+            (Key::Soft1, "Soft1"),
+            (Key::Soft2, "Soft2"),
+            (Key::Soft3, "Soft3"),
+            (Key::Soft4, "Soft4"),
+            (Key::ChannelDown, "ChannelDown"),
+            (Key::ChannelUp, "ChannelUp"),
+            (Key::Close, "Close"),
+            (Key::MailForward, "MailForward"),
+            (Key::MailReply, "MailReply"),
+            (Key::MailSend, "MailSend"),
+            (Key::MediaClose, "MediaClose"),
+            (Key::MediaFastForward, "MediaFastForward"),
+            (Key::MediaPause, "MediaPause"),
+            (Key::MediaPlay, "MediaPlay"),
+            (Key::MediaPlayPause, "MediaPlayPause"),
+            (Key::MediaRecord, "MediaRecord"),
+            (Key::MediaRewind, "MediaRewind"),
+            (Key::MediaStop, "MediaStop"),
+            (Key::MediaTrackNext, "MediaTrackNext"),
+            (Key::MediaTrackPrevious, "MediaTrackPrevious"),
+            (Key::New, "New"),
+            (Key::Open, "Open"),
+            (Key::Print, "Print"),
+            (Key::Save, "Save"),
+            (Key::SpellCheck, "SpellCheck"),
+            (Key::Key11, "Key11"),
+            (Key::Key12, "Key12"),
+            (Key::AudioBalanceLeft, "AudioBalanceLeft"),
+            (Key::AudioBalanceRight, "AudioBalanceRight"),
+            (Key::AudioBassBoostDown, "AudioBassBoostDown"),
+            (Key::AudioBassBoostToggle, "AudioBassBoostToggle"),
+            (Key::AudioBassBoostUp, "AudioBassBoostUp"),
+            (Key::AudioFaderFront, "AudioFaderFront"),
+            (Key::AudioFaderRear, "AudioFaderRear"),
+            (Key::AudioSurroundModeNext, "AudioSurroundModeNext"),
+            (Key::AudioTrebleDown, "AudioTrebleDown"),
+            (Key::AudioTrebleUp, "AudioTrebleUp"),
+            (Key::AudioVolumeDown, "AudioVolumeDown"),
+            (Key::AudioVolumeUp, "AudioVolumeUp"),
+            (Key::AudioVolumeMute, "AudioVolumeMute"),
+            (Key::MicrophoneToggle, "MicrophoneToggle"),
+            (Key::MicrophoneVolumeDown, "MicrophoneVolumeDown"),
+            (Key::MicrophoneVolumeUp, "MicrophoneVolumeUp"),
+            (Key::MicrophoneVolumeMute, "MicrophoneVolumeMute"),
+            (Key::SpeechCorrectionList, "SpeechCorrectionList"),
+            (Key::SpeechInputToggle, "SpeechInputToggle"),
+            (Key::LaunchApplication1, "LaunchApplication1"),
+            (Key::LaunchApplication2, "LaunchApplication2"),
+            (Key::LaunchCalendar, "LaunchCalendar"),
+            (Key::LaunchContacts, "LaunchContacts"),
+            (Key::LaunchMail, "LaunchMail"),
+            (Key::LaunchMediaPlayer, "LaunchMediaPlayer"),
+            (Key::LaunchMusicPlayer, "LaunchMusicPlayer"),
+            (Key::LaunchPhone, "LaunchPhone"),
+            (Key::LaunchScreenSaver, "LaunchScreenSaver"),
+            (Key::LaunchSpreadsheet, "LaunchSpreadsheet"),
+            (Key::LaunchWebBrowser, "LaunchWebBrowser"),
+            (Key::LaunchWebCam, "LaunchWebCam"),
+            (Key::LaunchWordProcessor, "LaunchWordProcessor"),
+            (Key::BrowserBack, "BrowserBack"),
+            (Key::BrowserFavorites, "BrowserFavorites"),
+            (Key::BrowserForward, "BrowserForward"),
+            (Key::BrowserHome, "BrowserHome"),
+            (Key::BrowserRefresh, "BrowserRefresh"),
+            (Key::BrowserSearch, "BrowserSearch"),
+            (Key::BrowserStop, "BrowserStop"),
+            (Key::AppSwitch, "AppSwitch"),
+            (Key::Call, "Call"),
+            (Key::Camera, "Camera"),
+            (Key::CameraFocus, "CameraFocus"),
+            (Key::EndCall, "EndCall"),
+            (Key::GoBack, "GoBack"),
+            (Key::GoHome, "GoHome"),
+            (Key::HeadsetHook, "HeadsetHook"),
+            (Key::LastNumberRedial, "LastNumberRedial"),
+            (Key::Notification, "Notification"),
+            (Key::MannerMode, "MannerMode"),
+            (Key::VoiceDial, "VoiceDial"),
+            (Key::TV, "TV"),
+            (Key::TV3DMode, "TV3DMode"),
+            (Key::TVAntennaCable, "TVAntennaCable"),
+            (Key::TVAudioDescription, "TVAudioDescription"),
+            (Key::TVAudioDescriptionMixDown, "TVAudioDescriptionMixDown"),
+            (Key::TVAudioDescriptionMixUp, "TVAudioDescriptionMixUp"),
+            (Key::TVContentsMenu, "TVContentsMenu"),
+            (Key::TVDataService, "TVDataService"),
+            (Key::TVInput, "TVInput"),
+            (Key::TVInputComponent1, "TVInputComponent1"),
+            (Key::TVInputComponent2, "TVInputComponent2"),
+            (Key::TVInputComposite1, "TVInputComposite1"),
+            (Key::TVInputComposite2, "TVInputComposite2"),
+            (Key::TVInputHDMI1, "TVInputHDMI1"),
+            (Key::TVInputHDMI2, "TVInputHDMI2"),
+            (Key::TVInputHDMI3, "TVInputHDMI3"),
+            (Key::TVInputHDMI4, "TVInputHDMI4"),
+            (Key::TVInputVGA1, "TVInputVGA1"),
+            (Key::TVMediaContext, "TVMediaContext"),
+            (Key::TVNetwork, "TVNetwork"),
+            (Key::TVNumberEntry, "TVNumberEntry"),
+            (Key::TVPower, "TVPower"),
+            (Key::TVRadioService, "TVRadioService"),
+            (Key::TVSatellite, "TVSatellite"),
+            (Key::TVSatelliteBS, "TVSatelliteBS"),
+            (Key::TVSatelliteCS, "TVSatelliteCS"),
+            (Key::TVSatelliteToggle, "TVSatelliteToggle"),
+            (Key::TVTerrestrialAnalog, "TVTerrestrialAnalog"),
+            (Key::TVTerrestrialDigital, "TVTerrestrialDigital"),
+            (Key::TVTimer, "TVTimer"),
+            (Key::AVRInput, "AVRInput"),
+            (Key::AVRPower, "AVRPower"),
+            (Key::ColorF0Red, "ColorF0Red"),
+            (Key::ColorF1Green, "ColorF1Green"),
+            (Key::ColorF2Yellow, "ColorF2Yellow"),
+            (Key::ColorF3Blue, "ColorF3Blue"),
+            (Key::ColorF4Grey, "ColorF4Grey"),
+            (Key::ColorF5Brown, "ColorF5Brown"),
+            (Key::ClosedCaptionToggle, "ClosedCaptionToggle"),
+            (Key::Dimmer, "Dimmer"),
+            (Key::DisplaySwap, "DisplaySwap"),
+            (Key::DVR, "DVR"),
+            (Key::Exit, "Exit"),
+            (Key::FavoriteClear0, "FavoriteClear0"),
+            (Key::FavoriteClear1, "FavoriteClear1"),
+            (Key::FavoriteClear2, "FavoriteClear2"),
+            (Key::FavoriteClear3, "FavoriteClear3"),
+            (Key::FavoriteRecall0, "FavoriteRecall0"),
+            (Key::FavoriteRecall1, "FavoriteRecall1"),
+            (Key::FavoriteRecall2, "FavoriteRecall2"),
+            (Key::FavoriteRecall3, "FavoriteRecall3"),
+            (Key::FavoriteStore0, "FavoriteStore0"),
+            (Key::FavoriteStore1, "FavoriteStore1"),
+            (Key::FavoriteStore2, "FavoriteStore2"),
+            (Key::FavoriteStore3, "FavoriteStore3"),
+            (Key::Guide, "Guide"),
+            (Key::GuideNextDay, "GuideNextDay"),
+            (Key::GuidePreviousDay, "GuidePreviousDay"),
+            (Key::Info, "Info"),
+            (Key::InstantReplay, "InstantReplay"),
+            (Key::Link, "Link"),
+            (Key::ListProgram, "ListProgram"),
+            (Key::LiveContent, "LiveContent"),
+            (Key::Lock, "Lock"),
+            (Key::MediaApps, "MediaApps"),
+            (Key::MediaAudioTrack, "MediaAudioTrack"),
+            (Key::MediaLast, "MediaLast"),
+            (Key::MediaSkipBackward, "MediaSkipBackward"),
+            (Key::MediaSkipForward, "MediaSkipForward"),
+            (Key::MediaStepBackward, "MediaStepBackward"),
+            (Key::MediaStepForward, "MediaStepForward"),
+            (Key::MediaTopMenu, "MediaTopMenu"),
+            (Key::NavigateIn, "NavigateIn"),
+            (Key::NavigateNext, "NavigateNext"),
+            (Key::NavigateOut, "NavigateOut"),
+            (Key::NavigatePrevious, "NavigatePrevious"),
+            (Key::NextFavoriteChannel, "NextFavoriteChannel"),
+            (Key::NextUserProfile, "NextUserProfile"),
+            (Key::OnDemand, "OnDemand"),
+            (Key::Pairing, "Pairing"),
+            (Key::PinPDown, "PinPDown"),
+            (Key::PinPMove, "PinPMove"),
+            (Key::PinPToggle, "PinPToggle"),
+            (Key::PinPUp, "PinPUp"),
+            (Key::PlaySpeedDown, "PlaySpeedDown"),
+            (Key::PlaySpeedReset, "PlaySpeedReset"),
+            (Key::PlaySpeedUp, "PlaySpeedUp"),
+            (Key::RandomToggle, "RandomToggle"),
+            (Key::RcLowBattery, "RcLowBattery"),
+            (Key::RecordSpeedNext, "RecordSpeedNext"),
+            (Key::RfBypass, "RfBypass"),
+            (Key::ScanChannelsToggle, "ScanChannelsToggle"),
+            (Key::ScreenModeNext, "ScreenModeNext"),
+            (Key::Settings, "Settings"),
+            (Key::SplitScreenToggle, "SplitScreenToggle"),
+            (Key::STBInput, "STBInput"),
+            (Key::STBPower, "STBPower"),
+            (Key::Subtitle, "Subtitle"),
+            (Key::Teletext, "Teletext"),
+            (Key::VideoModeNext, "VideoModeNext"),
+            (Key::Wink, "Wink"),
+            (Key::ZoomToggle, "ZoomToggle"),
+            (Key::F13, "F13"),
+            (Key::F14, "F14"),
+            (Key::F15, "F15"),
+            (Key::F16, "F16"),
+            (Key::F17, "F17"),
+            (Key::F18, "F18"),
+            (Key::F19, "F19"),
+            (Key::F20, "F20"),
+            (Key::F21, "F21"),
+            (Key::F22, "F22"),
+            (Key::F23, "F23"),
+            (Key::F24, "F24"),
+        ];
+        for (key, name) in names.iter() {
+            assert_eq!(key.telemetry_name(), *name);
+        }
+    }
+
+    #[test]
+    fn test_frozen_code_names() {
+        let names = [
+            (Code::Backquote, "Backquote"),
+            (Code::Backslash, "Backslash"),
+            (Code::BracketLeft, "BracketLeft"),
+            (Code::BracketRight, "BracketRight"),
+            (Code::Comma, "Comma"),
+            (Code::Digit0, "Digit0"),
+            (Code::Digit1, "Digit1"),
+            (Code::Digit2, "Digit2"),
+            (Code::Digit3, "Digit3"),
+            (Code::Digit4, "Digit4"),
+            (Code::Digit5, "Digit5"),
+            (Code::Digit6, "Digit6"),
+            (Code::Digit7, "Digit7"),
+            (Code::Digit8, "Digit8"),
+            (Code::Digit9, "Digit9"),
+            (Code::Equal, "Equal"),
+            (Code::IntlBackslash, "IntlBackslash"),
+            (Code::IntlRo, "IntlRo"),
+            (Code::IntlYen, "IntlYen"),
+            (Code::KeyA, "KeyA"),
+            (Code::KeyB, "KeyB"),
+            (Code::KeyC, "KeyC"),
+            (Code::KeyD, "KeyD"),
+            (Code::KeyE, "KeyE"),
+            (Code::KeyF, "KeyF"),
+            (Code::KeyG, "KeyG"),
+            (Code::KeyH, "KeyH"),
+            (Code::KeyI, "KeyI"),
+            (Code::KeyJ, "KeyJ"),
+            (Code::KeyK, "KeyK"),
+            (Code::KeyL, "KeyL"),
+            (Code::KeyM, "KeyM"),
+            (Code::KeyN, "KeyN"),
+            (Code::KeyO, "KeyO"),
+            (Code::KeyP, "KeyP"),
+            (Code::KeyQ, "KeyQ"),
+            (Code::KeyR, "KeyR"),
+            (Code::KeyS, "KeyS"),
+            (Code::KeyT, "KeyT"),
+            (Code::KeyU, "KeyU"),
+            (Code::KeyV, "KeyV"),
+            (Code::KeyW, "KeyW"),
+            (Code::KeyX, "KeyX"),
+            (Code::KeyY, "KeyY"),
+            (Code::KeyZ, "KeyZ"),
+            (Code::Minus, "Minus"),
+            (Code::Period, "Period"),
+            (Code::Quote, "Quote"),
+            (Code::Semicolon, "Semicolon"),
+            (Code::Slash, "Slash"),
+            (Code::AltLeft, "AltLeft"),
+            (Code::AltRight, "AltRight"),
+            (Code::Backspace, "Backspace"),
+            (Code::CapsLock, "CapsLock"),
+            (Code::ContextMenu, "ContextMenu"),
+            (Code::ControlLeft, "ControlLeft"),
+            (Code::ControlRight, "ControlRight"),
+            (Code::Enter, "Enter"),
+            (Code::MetaLeft, "MetaLeft"),
+            (Code::MetaRight, "MetaRight"),
+            (Code::ShiftLeft, "ShiftLeft"),
+            (Code::ShiftRight, "ShiftRight"),
+            (Code::Space, "Space"),
+            (Code::Tab, "Tab"),
+            (Code::Convert, "Convert"),
+            (Code::KanaMode, "KanaMode"),
+            (Code::Lang1, "Lang1"),
+            (Code::Lang2, "Lang2"),
+            (Code::Lang3, "Lang3"),
+            (Code::Lang4, "Lang4"),
+            (Code::Lang5, "Lang5"),
+            (Code::NonConvert, "NonConvert"),
+            (Code::Delete, "Delete"),
+            (Code::End, "End"),
+            (Code::Help, "Help"),
+            (Code::Home, "Home"),
+            (Code::Insert, "Insert"),
+            (Code::PageDown, "PageDown"),
+            (Code::PageUp, "PageUp"),
+            (Code::ArrowDown, "ArrowDown"),
+            (Code::ArrowLeft, "ArrowLeft"),
+            (Code::ArrowRight, "ArrowRight"),
+            (Code::ArrowUp, "ArrowUp"),
+            (Code::NumLock, "NumLock"),
+            (Code::Numpad0, "Numpad0"),
+            (Code::Numpad1, "Numpad1"),
+            (Code::Numpad2, "Numpad2"),
+            (Code::Numpad3, "Numpad3"),
+            (Code::Numpad4, "Numpad4"),
+            (Code::Numpad5, "Numpad5"),
+            (Code::Numpad6, "Numpad6"),
+            (Code::Numpad7, "Numpad7"),
+            (Code::Numpad8, "Numpad8"),
+            (Code::Numpad9, "Numpad9"),
+            (Code::NumpadAdd, "NumpadAdd"),
+            (Code::NumpadBackspace, "NumpadBackspace"),
+            (Code::NumpadClear, "NumpadClear"),
+            (Code::NumpadClearEntry, "NumpadClearEntry"),
+            (Code::NumpadComma, "NumpadComma"),
+            (Code::NumpadDecimal, "NumpadDecimal"),
+            (Code::NumpadDivide, "NumpadDivide"),
+            (Code::NumpadEnter, "NumpadEnter"),
+            (Code::NumpadEqual, "NumpadEqual"),
+            (Code::NumpadHash, "NumpadHash"),
+            (Code::NumpadMemoryAdd, "NumpadMemoryAdd"),
+            (Code::NumpadMemoryClear, "NumpadMemoryClear"),
+            (Code::NumpadMemoryRecall, "NumpadMemoryRecall"),
+            (Code::NumpadMemoryStore, "NumpadMemoryStore"),
+            (Code::NumpadMemorySubtract, "NumpadMemorySubtract"),
+            (Code::NumpadMultiply, "NumpadMultiply"),
+            (Code::NumpadParenLeft, "NumpadParenLeft"),
+            (Code::NumpadParenRight, "NumpadParenRight"),
+            (Code::NumpadStar, "NumpadStar"),
+            (Code::NumpadSubtract, "NumpadSubtract"),
+            (Code::Escape, "Escape"),
+            (Code::F1, "F1"),
+            (Code::F2, "F2"),
+            (Code::F3, "F3"),
+            (Code::F4, "F4"),
+            (Code::F5, "F5"),
+            (Code::F6, "F6"),
+            (Code::F7, "F7"),
+            (Code::F8, "F8"),
+            (Code::F9, "F9"),
+            (Code::F10, "F10"),
+            (Code::F11, "F11"),
+            (Code::F12, "F12"),
+            (Code::Fn, "Fn"),
+            (Code::FnLock, "FnLock"),
+            (Code::PrintScreen, "PrintScreen"),
+            (Code::ScrollLock, "ScrollLock"),
+            (Code::Pause, "Pause"),
+            (Code::BrowserBack, "BrowserBack"),
+            (Code::BrowserFavorites, "BrowserFavorites"),
+            (Code::BrowserForward, "BrowserForward"),
+            (Code::BrowserHome, "BrowserHome"),
+            (Code::BrowserRefresh, "BrowserRefresh"),
+            (Code::BrowserSearch, "BrowserSearch"),
+            (Code::BrowserStop, "BrowserStop"),
+            (Code::Eject, "Eject"),
+            (Code::LaunchApp1, "LaunchApp1"),
+            (Code::LaunchApp2, "LaunchApp2"),
+            (Code::LaunchMail, "LaunchMail"),
+            (Code::MediaPlayPause, "MediaPlayPause"),
+            (Code::MediaSelect, "MediaSelect"),
+            (Code::MediaStop, "MediaStop"),
+            (Code::MediaTrackNext, "MediaTrackNext"),
+            (Code::MediaTrackPrevious, "MediaTrackPrevious"),
+            (Code::Power, "Power"),
+            (Code::Sleep, "Sleep"),
+            (Code::AudioVolumeDown, "AudioVolumeDown"),
+            (Code::AudioVolumeMute, "AudioVolumeMute"),
+            (Code::AudioVolumeUp, "AudioVolumeUp"),
+            (Code::WakeUp, "WakeUp"),
+            (Code::Hyper, "Hyper"),
+            (Code::Super, "Super"),
+            (Code::Turbo, "Turbo"),
+            (Code::Abort, "Abort"),
+            (Code::Resume, "Resume"),
+            (Code::Suspend, "Suspend"),
+            (Code::Again, "Again"),
+            (Code::Copy, "Copy"),
+            (Code::Cut, "Cut"),
+            (Code::Find, "Find"),
+            (Code::Open, "Open"),
+            (Code::Paste, "Paste"),
+            (Code::Props, "Props"),
+            (Code::Select, "Select"),
+            (Code::Undo, "Undo"),
+            (Code::Hiragana, "Hiragana"),
+            (Code::Katakana, "Katakana"),
+            (Code::Unidentified, "Unidentified"),
+            (Code::F13, "F13"),
+            (Code::F14, "F14"),
+            (Code::F15, "F15"),
+            (Code::F16, "F16"),
+            (Code::F17, "F17"),
+            (Code::F18, "F18"),
+            (Code::F19, "F19"),
+            (Code::F20, "F20"),
+            (Code::F21, "F21"),
+            (Code::F22, "F22"),
+            (Code::F23, "F23"),
+            (Code::F24, "F24"),
+            (Code::BrightnessDown, "BrightnessDown"),
+            (Code::BrightnessUp, "BrightnessUp"),
+            (Code::DisplayToggleIntExt, "DisplayToggleIntExt"),
+            (Code::KeyboardLayoutSelect, "KeyboardLayoutSelect"),
+            (Code::LaunchAssistant, "LaunchAssistant"),
+            (Code::LaunchControlPanel, "LaunchControlPanel"),
+            (Code::LaunchScreenSaver, "LaunchScreenSaver"),
+            (Code::MailForward, "MailForward"),
+            (Code::MailReply, "MailReply"),
+            (Code::MailSend, "MailSend"),
+            (Code::MediaFastForward, "MediaFastForward"),
+            (Code::MediaPause, "MediaPause"),
+            (Code::MediaPlay, "MediaPlay"),
+            (Code::MediaRecord, "MediaRecord"),
+            (Code::MediaRewind, "MediaRewind"),
+            (Code::MicrophoneMuteToggle, "MicrophoneMuteToggle"),
+            (Code::PrivacyScreenToggle, "PrivacyScreenToggle"),
+            (Code::SelectTask, "SelectTask"),
+            (Code::ShowAllWindows, "ShowAllWindows"),
+            (Code::ZoomToggle, "ZoomToggle"),
+        ];
+        for (code, name) in names.iter() {
+            assert_eq!(code.telemetry_name(), *name);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_search_label() {
+        assert_eq!(Key::ArrowDown.search_label(), "Down");
+        assert_eq!(Key::Character(" ").search_label(), "Space");
+        assert_eq!(Key::Character("a\u{308}b").search_label(), "ab");
+        assert_eq!(Key::Dead.search_label(), "Dead");
+        assert_eq!(Key::F5.search_label(), "F5");
+        #[cfg(feature = "unicode")]
+        assert_eq!(Key::Character("\u{1EC7}").search_label(), "e");
+    }
+}
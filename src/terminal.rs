@@ -0,0 +1,318 @@
+//! Decode terminal input into keyboard events.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::mem;
+use core::ops::Range;
+
+use crate::{Key, KeyState, KeyboardEvent, Modifiers};
+
+const ESC: u8 = 0x1b;
+
+/// Decode the bytes read from a terminal into key events.
+///
+/// Recognizes the CSI and SS3 escape sequences sent by xterm compatible
+/// terminals for named keys like `\x1b[A` (ArrowUp), `\x1bOP` (F1) or
+/// `\x1b[15~` (F5), including their modifier parameters. Control bytes
+/// are reported as Control with a letter, like `\x01` for Control + A,
+/// except for Tab, Enter and Backspace. All other input is decoded as
+/// UTF-8 characters. An Escape before a character means the Alt
+/// modifier was held.
+///
+/// Terminals only report key presses, so all events are keydown
+/// events. The physical key is unknown and Shift is only reported as
+/// part of escape sequences.
+///
+/// Unknown input is reported as an unidentified key carrying the raw
+/// value: the number of an unknown `~` sequence like 29 for `\x1b[29~`,
+/// the final byte of other unknown sequences or the invalid UTF-8 byte.
+///
+/// Sequences split across multiple reads are buffered until they are
+/// complete. As the Escape key itself is the start of a sequence it is
+/// only reported when more input arrives or on [`flush`](Self::flush),
+/// which should be called when no input arrived for a short time.
+///
+/// ```rust
+/// # use gluten_keyboard::{Key, Modifiers, TerminalDecoder};
+/// let mut decoder = TerminalDecoder::new();
+/// assert!(decoder.feed(b"\x1b[1;").is_empty());
+/// let events = decoder.feed(b"5Ca\x01");
+/// assert_eq!(events[0].key, Key::ArrowRight);
+/// assert_eq!(events[0].modifiers, Modifiers::CONTROL);
+/// assert_eq!(events[1].key, Key::Character("a"));
+/// assert_eq!(events[2].key, Key::Character("a"));
+/// assert_eq!(events[2].modifiers, Modifiers::CONTROL);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TerminalDecoder {
+    pending: Vec<u8>,
+    text: String,
+}
+
+/// A decoded key before the event is built.
+enum Decoded {
+    Named(Key<'static>),
+    /// A character stored in the text buffer.
+    Text(Range<usize>),
+}
+
+impl TerminalDecoder {
+    /// Create a decoder with no buffered input.
+    pub fn new() -> TerminalDecoder {
+        TerminalDecoder::default()
+    }
+
+    /// Decode the next chunk of input.
+    ///
+    /// Incomplete sequences at the end are kept for the next call.
+    /// The character values of the events borrow from the decoder.
+    pub fn feed(&mut self, bytes: &[u8]) -> Vec<KeyboardEvent<'_>> {
+        self.text.clear();
+        self.pending.extend_from_slice(bytes);
+        let input = mem::take(&mut self.pending);
+        let mut decoded = Vec::new();
+        let mut i = 0;
+        while i < input.len() {
+            match decode(&input[i..], &mut self.text) {
+                Some((key, modifiers, len)) => {
+                    decoded.push((key, modifiers));
+                    i += len;
+                }
+                None => break,
+            }
+        }
+        self.pending.extend_from_slice(&input[i..]);
+        self.events(decoded)
+    }
+
+    /// Decode the buffered input even if it is incomplete.
+    ///
+    /// A buffered Escape is reported as the Escape key and
+    /// incomplete characters as unidentified keys.
+    pub fn flush(&mut self) -> Vec<KeyboardEvent<'_>> {
+        self.text.clear();
+        let input = mem::take(&mut self.pending);
+        let mut decoded = Vec::new();
+        let mut i = 0;
+        while i < input.len() {
+            if input[i] == ESC {
+                match decode_unit(&input[i + 1..], &mut self.text) {
+                    Some((key, modifiers, len)) => {
+                        decoded.push((key, modifiers | Modifiers::ALT));
+                        i += 1 + len;
+                    }
+                    None => {
+                        decoded.push((Decoded::Named(Key::Escape), Modifiers::empty()));
+                        i += 1;
+                    }
+                }
+                continue;
+            }
+            match decode_unit(&input[i..], &mut self.text) {
+                Some((key, modifiers, len)) => {
+                    decoded.push((key, modifiers));
+                    i += len;
+                }
+                None => {
+                    let key = Key::Unidentified(Some(input[i].into()));
+                    decoded.push((Decoded::Named(key), Modifiers::empty()));
+                    i += 1;
+                }
+            }
+        }
+        self.events(decoded)
+    }
+
+    fn events(&self, decoded: Vec<(Decoded, Modifiers)>) -> Vec<KeyboardEvent<'_>> {
+        decoded
+            .into_iter()
+            .map(|(key, modifiers)| KeyboardEvent {
+                state: KeyState::Down,
+                key: match key {
+                    Decoded::Named(key) => key,
+                    Decoded::Text(range) => Key::Character(&self.text[range]),
+                },
+                modifiers,
+                ..Default::default()
+            })
+            .collect()
+    }
+}
+
+/// Decode a key at the start of the input.
+///
+/// Returns `None` if the input is incomplete.
+fn decode(input: &[u8], text: &mut String) -> Option<(Decoded, Modifiers, usize)> {
+    if input[0] != ESC {
+        return decode_unit(input, text);
+    }
+    match input.get(1)? {
+        b'[' => {
+            let end = 2 + input[2..].iter().position(|b| (0x40..=0x7e).contains(b))?;
+            let (key, modifiers) = decode_csi(&input[2..end], input[end]);
+            Some((Decoded::Named(key), modifiers, end + 1))
+        }
+        b'O' => {
+            let (key, modifiers) = match *input.get(2)? {
+                // The keypad Enter key in application mode. `ESC [ M` is
+                // instead the start of a mouse report.
+                b'M' => (Key::Enter, Modifiers::empty()),
+                last => decode_csi(&[], last),
+            };
+            Some((Decoded::Named(key), modifiers, 3))
+        }
+        &ESC => Some((Decoded::Named(Key::Escape), Modifiers::empty(), 1)),
+        _ => {
+            let (key, modifiers, len) = decode_unit(&input[1..], text)?;
+            Some((key, modifiers | Modifiers::ALT, len + 1))
+        }
+    }
+}
+
+/// Decode a control byte or UTF-8 character.
+fn decode_unit(input: &[u8], text: &mut String) -> Option<(Decoded, Modifiers, usize)> {
+    let first = *input.first()?;
+    let named = |key| Some((Decoded::Named(key), Modifiers::empty(), 1));
+    match first {
+        b'\t' => return named(Key::Tab),
+        b'\r' | b'\n' => return named(Key::Enter),
+        0x08 | 0x7f => return named(Key::Backspace),
+        ESC => return named(Key::Escape),
+        0x00..=0x1f => {
+            // Control + @, A to Z, [, \, ], ^ and _.
+            let start = text.len();
+            text.push(((first + 0x40) as char).to_ascii_lowercase());
+            return Some((Decoded::Text(start..text.len()), Modifiers::CONTROL, 1));
+        }
+        _ => {}
+    }
+    let len = match first {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf7 => 4,
+        _ => return named(Key::Unidentified(Some(first.into()))),
+    };
+    let bytes = input.get(..len);
+    if bytes.is_none() && input[1..].iter().all(|b| b & 0xc0 == 0x80) {
+        return None;
+    }
+    match bytes.map(core::str::from_utf8) {
+        Some(Ok(s)) => {
+            let start = text.len();
+            text.push_str(s);
+            Some((Decoded::Text(start..text.len()), Modifiers::empty(), len))
+        }
+        _ => named(Key::Unidentified(Some(first.into()))),
+    }
+}
+
+/// Decode the parameters and final byte of a CSI or SS3 sequence.
+fn decode_csi(params: &[u8], last: u8) -> (Key<'static>, Modifiers) {
+    let mut numbers = params.split(|b| *b == b';').map(|n| {
+        core::str::from_utf8(n)
+            .ok()
+            .and_then(|n| n.parse::<u32>().ok())
+    });
+    let number = numbers.next().flatten().unwrap_or(1);
+    let modifier = numbers.next().flatten().unwrap_or(1).saturating_sub(1);
+    let mut modifiers = Modifiers::empty();
+    modifiers.set(Modifiers::SHIFT, modifier & 1 != 0);
+    modifiers.set(Modifiers::ALT, modifier & 2 != 0);
+    modifiers.set(Modifiers::CONTROL, modifier & 4 != 0);
+    modifiers.set(Modifiers::META, modifier & 8 != 0);
+    let key = match last {
+        b'A' => Key::ArrowUp,
+        b'B' => Key::ArrowDown,
+        b'C' => Key::ArrowRight,
+        b'D' => Key::ArrowLeft,
+        b'H' => Key::Home,
+        b'F' => Key::End,
+        b'P' => Key::F1,
+        b'Q' => Key::F2,
+        b'R' => Key::F3,
+        b'S' => Key::F4,
+        b'Z' => {
+            modifiers |= Modifiers::SHIFT;
+            Key::Tab
+        }
+        b'~' => match number {
+            1 | 7 => Key::Home,
+            2 => Key::Insert,
+            3 => Key::Delete,
+            4 | 8 => Key::End,
+            5 => Key::PageUp,
+            6 => Key::PageDown,
+            11 => Key::F1,
+            12 => Key::F2,
+            13 => Key::F3,
+            14 => Key::F4,
+            15 => Key::F5,
+            17 => Key::F6,
+            18 => Key::F7,
+            19 => Key::F8,
+            20 => Key::F9,
+            21 => Key::F10,
+            23 => Key::F11,
+            24 => Key::F12,
+            _ => Key::Unidentified(Some(number)),
+        },
+        _ => Key::Unidentified(Some(last.into())),
+    };
+    (key, modifiers)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn keys<'a>(events: &[KeyboardEvent<'a>]) -> Vec<(Key<'a>, Modifiers)> {
+        events.iter().map(|e| (e.key, e.modifiers)).collect()
+    }
+
+    #[test]
+    fn test_decode() {
+        let mut decoder = TerminalDecoder::new();
+        assert_eq!(
+            keys(&decoder.feed(b"\x1b[A\x1bOP\x1b[15~\x1b[Z\x1b[3;3~")),
+            [
+                (Key::ArrowUp, Modifiers::empty()),
+                (Key::F1, Modifiers::empty()),
+                (Key::F5, Modifiers::empty()),
+                (Key::Tab, Modifiers::SHIFT),
+                (Key::Delete, Modifiers::ALT),
+            ]
+        );
+        assert_eq!(
+            keys(&decoder.feed(b"\x01\r\x7f\x1bx\xc3")),
+            [
+                (Key::Character("a"), Modifiers::CONTROL),
+                (Key::Enter, Modifiers::empty()),
+                (Key::Backspace, Modifiers::empty()),
+                (Key::Character("x"), Modifiers::ALT),
+            ]
+        );
+        assert_eq!(
+            keys(&decoder.feed(b"\xa9\xff\x1b")),
+            [
+                (Key::Character("\u{e9}"), Modifiers::empty()),
+                (Key::Unidentified(Some(0xff)), Modifiers::empty()),
+            ]
+        );
+        assert_eq!(keys(&decoder.flush()), [(Key::Escape, Modifiers::empty())]);
+        assert!(decoder.feed(b"\x1bO").is_empty());
+        assert_eq!(
+            keys(&decoder.flush()),
+            [(Key::Character("O"), Modifiers::ALT)]
+        );
+        assert_eq!(
+            keys(&decoder.feed(b"\x1b[29~\x1bOX\x1bOM\x1b[M")),
+            [
+                (Key::Unidentified(Some(29)), Modifiers::empty()),
+                (Key::Unidentified(Some(b'X'.into())), Modifiers::empty()),
+                (Key::Enter, Modifiers::empty()),
+                (Key::Unidentified(Some(b'M'.into())), Modifiers::empty()),
+            ]
+        );
+    }
+}
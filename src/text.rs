@@ -0,0 +1,112 @@
+//! Reconstruct typed text from recorded events.
+
+use alloc::string::String;
+
+use crate::{CompositionEvent, CompositionState, Key, KeyState, KeyboardEvent, Modifiers};
+
+/// Reconstruct the text typed by a recorded stream of events.
+///
+/// Key presses with a character value append the character, Enter and
+/// Tab append a newline and a tab and Backspace removes the last
+/// character. Presses with Control or Meta held are shortcuts and
+/// don't produce text.
+///
+/// Key events with `is_composing` set are skipped. Instead the data of the
+/// "compositionend" events is inserted when the composition is over,
+/// that is before the first key event after a run of composing events.
+/// Compositions left over after the last key event are appended at the end.
+///
+/// ```rust
+/// # use gluten_keyboard::{extract_text, CompositionEvent, CompositionState, Key, KeyboardEvent};
+/// let key = |key, is_composing| KeyboardEvent {
+///     key,
+///     is_composing,
+///     ..Default::default()
+/// };
+/// let events = [
+///     key(Key::Character("a"), false),
+///     key(Key::Dead, false),
+///     key(Key::Character("e"), true),
+///     key(Key::Character("!"), false),
+/// ];
+/// let compositions = [
+///     CompositionEvent { state: CompositionState::Start, data: "" },
+///     CompositionEvent { state: CompositionState::End, data: "é" },
+/// ];
+/// assert_eq!(extract_text(&events, &compositions), "aé!");
+/// ```
+pub fn extract_text(events: &[KeyboardEvent], compositions: &[CompositionEvent]) -> String {
+    let mut text = String::new();
+    let mut ends = compositions
+        .iter()
+        .filter(|c| c.state == CompositionState::End);
+    let mut composing = false;
+    for event in events {
+        if event.is_composing {
+            composing = true;
+            continue;
+        }
+        if composing {
+            composing = false;
+            if let Some(end) = ends.next() {
+                text.push_str(end.data);
+            }
+        }
+        if event.state == KeyState::Up
+            || event
+                .modifiers
+                .intersects(Modifiers::CONTROL | Modifiers::META)
+        {
+            continue;
+        }
+        match event.key {
+            Key::Character(s) => text.push_str(s),
+            Key::Enter => text.push('\n'),
+            Key::Tab => text.push('\t'),
+            Key::Backspace => {
+                text.pop();
+            }
+            _ => {}
+        }
+    }
+    for end in ends {
+        text.push_str(end.data);
+    }
+    text
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn press(key: Key<'static>) -> KeyboardEvent<'static> {
+        KeyboardEvent {
+            key,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_extract_text() {
+        let mut release = press(Key::Character("x"));
+        release.state = KeyState::Up;
+        let mut shortcut = press(Key::Character("s"));
+        shortcut.modifiers = Modifiers::CONTROL;
+        let mut composing = press(Key::Process);
+        composing.is_composing = true;
+        let events = [
+            press(Key::Character("a")),
+            press(Key::Character("b")),
+            press(Key::Backspace),
+            release,
+            shortcut,
+            press(Key::Enter),
+            composing,
+        ];
+        let compositions = [CompositionEvent {
+            state: CompositionState::End,
+            data: "日本",
+        }];
+        assert_eq!(extract_text(&events, &compositions), "a\n日本");
+    }
+}
@@ -0,0 +1,113 @@
+use core::time::Duration;
+
+use crate::{KeyState, KeyboardEvent};
+
+/// A keyboard event with the time it occurred.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct TimedEvent<'a> {
+    /// The keyboard event.
+    pub event: KeyboardEvent<'a>,
+
+    /// Time of the event, relative to an arbitrary but fixed point in time.
+    ///
+    /// Usually this is the timestamp provided by the platform.
+    pub time: Duration,
+}
+
+/// Detect double presses of the same key, like pressing Shift twice.
+///
+/// A double press is reported when a key is pressed again within the
+/// threshold after it was first pressed. Pressing a different key in
+/// between resets the detector. Key releases and auto-repeated
+/// events are ignored.
+///
+/// ```rust
+/// # use core::time::Duration;
+/// # use gluten_keyboard::{Code, DoublePressDetector, Key, KeyboardEvent, TimedEvent};
+/// let mut detector = DoublePressDetector::new(Duration::from_millis(300));
+/// let shift = |millis| TimedEvent {
+///     event: KeyboardEvent {
+///         key: Key::Shift,
+///         code: Code::ShiftLeft,
+///         ..Default::default()
+///     },
+///     time: Duration::from_millis(millis),
+/// };
+/// assert!(!detector.update(&shift(1000)));
+/// assert!(detector.update(&shift(1200)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct DoublePressDetector<'a> {
+    threshold: Duration,
+    last: Option<TimedEvent<'a>>,
+}
+
+impl<'a> DoublePressDetector<'a> {
+    /// Create a detector reporting presses within the threshold.
+    pub fn new(threshold: Duration) -> DoublePressDetector<'a> {
+        DoublePressDetector {
+            threshold,
+            last: None,
+        }
+    }
+
+    /// Feed an event into the detector.
+    ///
+    /// Returns true if the event is the second press of a double press.
+    /// After a double press the detector starts over, so a third press
+    /// is not reported again.
+    pub fn update(&mut self, ev: &TimedEvent<'a>) -> bool {
+        if ev.event.state != KeyState::Down || ev.event.repeat {
+            return false;
+        }
+        if let Some(last) = self.last.take() {
+            let within = ev
+                .time
+                .checked_sub(last.time)
+                .map_or(false, |elapsed| elapsed <= self.threshold);
+            if within && last.event.same_physical_key(&ev.event) {
+                return true;
+            }
+        }
+        self.last = Some(ev.clone());
+        false
+    }
+
+    /// Forget the last key press.
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Code, Key};
+
+    fn press(code: Code, millis: u64) -> TimedEvent<'static> {
+        TimedEvent {
+            event: KeyboardEvent {
+                key: Key::Unidentified(None),
+                code,
+                ..Default::default()
+            },
+            time: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn test_double_press() {
+        let mut detector = DoublePressDetector::new(Duration::from_millis(300));
+        assert!(!detector.update(&press(Code::ShiftLeft, 0)));
+        assert!(!detector.update(&press(Code::ShiftLeft, 500)));
+        assert!(detector.update(&press(Code::ShiftLeft, 700)));
+        assert!(!detector.update(&press(Code::ShiftLeft, 800)));
+
+        assert!(!detector.update(&press(Code::KeyA, 900)));
+        assert!(!detector.update(&press(Code::ShiftLeft, 1000)));
+        let mut release = press(Code::ShiftLeft, 1050);
+        release.event.state = KeyState::Up;
+        assert!(!detector.update(&release));
+        assert!(detector.update(&press(Code::ShiftLeft, 1100)));
+    }
+}
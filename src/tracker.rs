@@ -0,0 +1,480 @@
+//! Track the state of the keyboard from a stream of key events.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::code::CODES;
+use crate::modifiers::LOCKS;
+#[cfg(feature = "alloc")]
+use crate::Layout;
+use crate::{Code, KeyState, KeyboardEvent, Location, Modifiers};
+
+/// Derive the modifier state from key events.
+///
+/// Held modifiers like Shift are set while the key is down.
+/// The left and right instances of a modifier key are tracked separately,
+/// so releasing one Shift key while the other is still held keeps the flag.
+/// Lock modifiers like CapsLock are toggled on every keydown.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ModifierTracker {
+    held: [Modifiers; 4],
+    locks: Modifiers,
+}
+
+impl ModifierTracker {
+    /// Create a tracker with no modifiers active.
+    pub fn new() -> ModifierTracker {
+        ModifierTracker::default()
+    }
+
+    /// Update the modifier state with a key event.
+    ///
+    /// Events for keys that are not modifiers are ignored.
+    pub fn update(&mut self, event: &KeyboardEvent) {
+        let flag = match event.key.modifier_flag() {
+            Some(flag) => flag,
+            None => return,
+        };
+        if LOCKS.contains(flag) {
+            if event.state == KeyState::Down && !event.repeat {
+                self.locks.toggle(flag);
+            }
+            return;
+        }
+        let held = &mut self.held[location_index(event.location)];
+        match event.state {
+            KeyState::Down => held.insert(flag),
+            KeyState::Up => held.remove(flag),
+        }
+    }
+
+    /// Get the currently active modifiers.
+    pub fn modifiers(&self) -> Modifiers {
+        self.held.iter().fold(self.locks, |acc, held| acc | *held)
+    }
+
+    /// Release all held modifiers.
+    ///
+    /// The lock states are kept as they belong to the keyboard
+    /// and not to the keys currently pressed.
+    pub fn clear(&mut self) {
+        self.held = Default::default();
+    }
+}
+
+fn location_index(location: Location) -> usize {
+    match location {
+        Location::Standard => 0,
+        Location::Left => 1,
+        Location::Right => 2,
+        Location::Numpad => 3,
+    }
+}
+
+/// Keep track of the keys currently held down.
+///
+/// Keys are identified by their `Code`. Events with an unidentified
+/// code are only used to update the modifier state.
+///
+/// When the window loses focus the keyup events for the held keys
+/// are never delivered. Call [`PressedKeys::release_all`] on focus loss
+/// to synthesize them, so no keys get stuck.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default)]
+pub struct PressedKeys<'a> {
+    pressed: Vec<KeyboardEvent<'a>>,
+    modifiers: ModifierTracker,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> PressedKeys<'a> {
+    /// Create a tracker with no keys pressed.
+    pub fn new() -> PressedKeys<'a> {
+        PressedKeys::default()
+    }
+
+    /// Update the state with a key event.
+    pub fn update(&mut self, event: &KeyboardEvent<'a>) {
+        self.modifiers.update(event);
+        if event.code == Code::Unidentified {
+            return;
+        }
+        let position = self.pressed.iter().position(|e| e.code == event.code);
+        match (event.state, position) {
+            (KeyState::Down, None) => self.pressed.push(event.clone()),
+            (KeyState::Up, Some(index)) => {
+                self.pressed.remove(index);
+            }
+            _ => {}
+        }
+    }
+
+    /// Check if the key at the physical position is held down.
+    pub fn is_pressed(&self, code: Code) -> bool {
+        self.pressed.iter().any(|e| e.code == code)
+    }
+
+    /// Iterate over the codes of all held keys in the order they were pressed.
+    pub fn codes(&self) -> impl Iterator<Item = Code> + '_ {
+        self.pressed.iter().map(|e| e.code)
+    }
+
+    /// Get the modifier state derived from the events.
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers.modifiers()
+    }
+
+    /// Release all keys and return the keyup events for them.
+    ///
+    /// The keys are released in reverse order of being pressed.
+    /// Each event carries the modifiers active after its key was released.
+    /// Held modifiers are cleared afterwards, lock states are kept.
+    pub fn release_all(&mut self) -> Vec<KeyboardEvent<'a>> {
+        let mut released = Vec::with_capacity(self.pressed.len());
+        while let Some(mut event) = self.pressed.pop() {
+            event.state = KeyState::Up;
+            event.repeat = false;
+            event.is_composing = false;
+            self.modifiers.update(&event);
+            event.modifiers = self.modifiers.modifiers();
+            released.push(event);
+        }
+        self.modifiers.clear();
+        released
+    }
+}
+
+/// Remove redundant events from a stream of key events.
+///
+/// Keys are identified by their `Code`, starting with no keys held:
+///
+/// - A keydown of a modifier or lock key which is already held is removed.
+///   Repeated keydowns of other keys are auto-repeat and kept.
+/// - A keyup of a key which is not held is removed. This includes keys
+///   pressed before the first event.
+/// - Events with an unidentified code are always kept.
+///
+/// The order of the remaining events is unchanged.
+///
+/// ```rust
+/// # use gluten_keyboard::{coalesce, Code, Key, KeyboardEvent, KeyState};
+/// let shift = KeyboardEvent {
+///     key: Key::Shift,
+///     code: Code::ShiftLeft,
+///     ..Default::default()
+/// };
+/// let release = KeyboardEvent {
+///     state: KeyState::Up,
+///     ..shift.clone()
+/// };
+/// let mut events = vec![release.clone(), shift.clone(), shift, release];
+/// coalesce(&mut events);
+/// assert_eq!(events.len(), 2);
+/// assert_eq!(events[0].state, KeyState::Down);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn coalesce(events: &mut Vec<KeyboardEvent>) {
+    let mut down = CodeSet::default();
+    events.retain(|event| {
+        if event.code == Code::Unidentified {
+            return true;
+        }
+        let held = down.contains(event.code);
+        match event.state {
+            KeyState::Down => {
+                down.insert(event.code);
+                !held || event.key.modifier_flag().is_none()
+            }
+            KeyState::Up => {
+                down.remove(event.code);
+                held
+            }
+        }
+    });
+}
+
+/// Snapshot of the held keys for immediate mode input handling.
+///
+/// Games and similar applications ask for the state of keys once per
+/// frame instead of reacting to events. Feed all events to
+/// [`apply`](Self::apply) and call [`new_frame`](Self::new_frame) at the
+/// start of each frame. All queries are constant time bit tests.
+///
+/// ```rust
+/// # use gluten_keyboard::{Code, KeyboardEvent, KeyboardState};
+/// let mut state = KeyboardState::new();
+/// state.apply(&KeyboardEvent {
+///     code: Code::Space,
+///     ..Default::default()
+/// });
+/// assert!(state.is_down(Code::Space));
+/// assert!(state.just_pressed(Code::Space));
+/// state.new_frame();
+/// assert!(state.is_down(Code::Space));
+/// assert!(!state.just_pressed(Code::Space));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct KeyboardState {
+    down: CodeSet,
+    pressed: CodeSet,
+    released: CodeSet,
+}
+
+impl KeyboardState {
+    /// Create a state with no keys held.
+    pub fn new() -> KeyboardState {
+        KeyboardState::default()
+    }
+
+    /// Update the state with a key event.
+    ///
+    /// Auto-repeated events and events with an unidentified code are ignored.
+    pub fn apply(&mut self, event: &KeyboardEvent) {
+        if event.repeat || event.code == Code::Unidentified {
+            return;
+        }
+        match event.state {
+            KeyState::Down => {
+                self.down.insert(event.code);
+                self.pressed.insert(event.code);
+            }
+            KeyState::Up => {
+                self.down.remove(event.code);
+                self.released.insert(event.code);
+            }
+        }
+    }
+
+    /// Start a new frame, forgetting which keys were pressed or released.
+    pub fn new_frame(&mut self) {
+        self.pressed = CodeSet::default();
+        self.released = CodeSet::default();
+    }
+
+    /// Check if the key is held down.
+    pub fn is_down(&self, code: Code) -> bool {
+        self.down.contains(code)
+    }
+
+    /// Check if the key was pressed during the current frame.
+    ///
+    /// A key pressed and released within the frame counts as pressed
+    /// even though it is no longer down.
+    pub fn just_pressed(&self, code: Code) -> bool {
+        self.pressed.contains(code)
+    }
+
+    /// Check if the key was released during the current frame.
+    pub fn just_released(&self, code: Code) -> bool {
+        self.released.contains(code)
+    }
+
+    /// Compute the events changing this state into the target state.
+    ///
+    /// First keyup events are generated for all keys not held in the
+    /// target, then keydown events for the newly held keys. Modifier keys
+    /// are released after and pressed before the other keys. The keys are
+    /// resolved with the layout and the modifiers derived from the held
+    /// modifier keys, so both are only as accurate as the layout.
+    /// Lock states are not part of the state and thus unknown.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::{Code, Key, KeyboardEvent, KeyboardState, KeyState, Modifiers, UsQwerty};
+    /// let mut target = KeyboardState::new();
+    /// for code in &[Code::ShiftLeft, Code::KeyA] {
+    ///     target.apply(&KeyboardEvent {
+    ///         code: *code,
+    ///         ..Default::default()
+    ///     });
+    /// }
+    /// let events = KeyboardState::new().diff_events(&target, &UsQwerty);
+    /// assert_eq!(events.len(), 2);
+    /// assert_eq!(events[1].key, Key::Character("A"));
+    /// assert_eq!(events[1].modifiers, Modifiers::SHIFT);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn diff_events<'a>(
+        &self,
+        target: &KeyboardState,
+        layout: &'a dyn Layout,
+    ) -> Vec<KeyboardEvent<'a>> {
+        let mut modifiers = ModifierTracker::new();
+        for code in self.down.iter() {
+            modifiers.update(&KeyboardEvent::from_physical(
+                KeyState::Down,
+                code,
+                Modifiers::empty(),
+                layout,
+            ));
+        }
+        let is_modifier = |code: Code| layout.resolve_level(code, 1).modifier_flag().is_some();
+        let mut released: Vec<Code> = self.down.iter().filter(|c| !target.is_down(*c)).collect();
+        let mut pressed: Vec<Code> = target.down.iter().filter(|c| !self.is_down(*c)).collect();
+        // Modifiers are pressed first and released last
+        // so they apply to the other keys.
+        released.sort_by_key(|code| is_modifier(*code));
+        pressed.sort_by_key(|code| !is_modifier(*code));
+        let changes = (released.into_iter().map(|code| (KeyState::Up, code)))
+            .chain(pressed.into_iter().map(|code| (KeyState::Down, code)));
+        let mut events = Vec::new();
+        for (state, code) in changes {
+            let mut event =
+                KeyboardEvent::from_physical(state, code, modifiers.modifiers(), layout);
+            modifiers.update(&event);
+            event.modifiers = modifiers.modifiers();
+            events.push(event);
+        }
+        events
+    }
+}
+
+/// Fixed size set of codes indexed by their discriminant.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+struct CodeSet([u64; 4]);
+
+impl CodeSet {
+    fn insert(&mut self, code: Code) {
+        let (word, bit) = code_bit(code);
+        self.0[word] |= bit;
+    }
+
+    fn remove(&mut self, code: Code) {
+        let (word, bit) = code_bit(code);
+        self.0[word] &= !bit;
+    }
+
+    fn contains(&self, code: Code) -> bool {
+        let (word, bit) = code_bit(code);
+        self.0[word] & bit != 0
+    }
+
+    #[cfg(feature = "alloc")]
+    fn iter(&self) -> impl Iterator<Item = Code> + '_ {
+        CODES
+            .iter()
+            .copied()
+            .filter(move |code| self.contains(*code))
+    }
+}
+
+fn code_bit(code: Code) -> (usize, u64) {
+    let index = code as usize;
+    (index / 64, 1 << (index % 64))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Key;
+
+    fn event(state: KeyState, key: Key<'static>, code: Code) -> KeyboardEvent<'static> {
+        KeyboardEvent {
+            state,
+            key,
+            code,
+            location: Location::Left,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_keyboard_state() {
+        // The set must have room for the last code.
+        assert!((Code::ZoomToggle as usize) < 256);
+
+        let mut state = KeyboardState::new();
+        state.apply(&event(KeyState::Down, Key::Character("a"), Code::KeyA));
+        state.apply(&event(KeyState::Down, Key::ZoomToggle, Code::ZoomToggle));
+        state.apply(&event(KeyState::Up, Key::Character("a"), Code::KeyA));
+        assert!(!state.is_down(Code::KeyA));
+        assert!(state.just_pressed(Code::KeyA));
+        assert!(state.just_released(Code::KeyA));
+        assert!(state.is_down(Code::ZoomToggle));
+
+        state.new_frame();
+        let mut repeat = event(KeyState::Down, Key::Character("a"), Code::KeyA);
+        repeat.repeat = true;
+        state.apply(&repeat);
+        assert!(!state.just_pressed(Code::KeyA));
+        assert!(!state.just_released(Code::KeyA));
+        assert!(state.is_down(Code::ZoomToggle));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_diff_events() {
+        for (index, code) in CODES.iter().enumerate() {
+            assert_eq!(*code as usize, index);
+        }
+
+        let mut current = KeyboardState::new();
+        let mut target = KeyboardState::new();
+        for code in &[Code::ControlLeft, Code::KeyC] {
+            current.apply(&event(KeyState::Down, Key::Unidentified(None), *code));
+        }
+        for code in &[Code::KeyC, Code::KeyV] {
+            target.apply(&event(KeyState::Down, Key::Unidentified(None), *code));
+        }
+        let events = current.diff_events(&target, &crate::UsQwerty);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].state, KeyState::Up);
+        assert_eq!(events[0].key, Key::Control);
+        assert_eq!(events[0].location, Location::Left);
+        assert_eq!(events[0].modifiers, Modifiers::empty());
+        assert_eq!(events[1].state, KeyState::Down);
+        assert_eq!(events[1].key, Key::Character("v"));
+        assert!(current.diff_events(&current, &crate::UsQwerty).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_coalesce() {
+        let mut events = alloc::vec![
+            event(KeyState::Up, Key::Character("a"), Code::KeyA),
+            event(KeyState::Down, Key::CapsLock, Code::CapsLock),
+            event(KeyState::Down, Key::CapsLock, Code::CapsLock),
+            event(KeyState::Down, Key::Character("a"), Code::KeyA),
+            event(KeyState::Down, Key::Character("a"), Code::KeyA),
+            event(KeyState::Up, Key::Character("a"), Code::KeyA),
+            event(KeyState::Up, Key::Character("a"), Code::KeyA),
+            event(KeyState::Up, Key::Unidentified(None), Code::Unidentified),
+            event(KeyState::Up, Key::CapsLock, Code::CapsLock),
+        ];
+        coalesce(&mut events);
+        let states: Vec<_> = events.iter().map(|e| (e.state, e.code)).collect();
+        assert_eq!(
+            states,
+            [
+                (KeyState::Down, Code::CapsLock),
+                (KeyState::Down, Code::KeyA),
+                (KeyState::Down, Code::KeyA),
+                (KeyState::Up, Code::KeyA),
+                (KeyState::Up, Code::Unidentified),
+                (KeyState::Up, Code::CapsLock),
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_release_all() {
+        let mut pressed = PressedKeys::new();
+        pressed.update(&event(KeyState::Down, Key::Shift, Code::ShiftLeft));
+        pressed.update(&event(KeyState::Down, Key::Character("A"), Code::KeyA));
+        pressed.update(&event(KeyState::Down, Key::Character("B"), Code::KeyB));
+        pressed.update(&event(KeyState::Up, Key::Character("B"), Code::KeyB));
+        assert!(pressed.is_pressed(Code::KeyA));
+        assert_eq!(pressed.modifiers(), Modifiers::SHIFT);
+
+        let released = pressed.release_all();
+        assert_eq!(released.len(), 2);
+        assert_eq!(released[0].code, Code::KeyA);
+        assert_eq!(released[0].state, KeyState::Up);
+        assert_eq!(released[0].modifiers, Modifiers::SHIFT);
+        assert_eq!(released[1].code, Code::ShiftLeft);
+        assert_eq!(released[1].modifiers, Modifiers::empty());
+        assert_eq!(pressed.codes().count(), 0);
+        assert_eq!(pressed.modifiers(), Modifiers::empty());
+    }
+}
@@ -0,0 +1,104 @@
+//! Canonical composition of characters.
+//!
+//! A small replacement for full Unicode normalization, enough to compare
+//! key values typed on international keyboards. Only the compositions
+//! listed in [`COMPOSITIONS`] are applied and combining marks are expected
+//! in canonical order.
+
+use crate::unicode_table::COMPOSITIONS;
+use crate::Key;
+
+impl Key<'_> {
+    /// Compare two keys treating equivalent character sequences as equal.
+    ///
+    /// Character values are compared after canonical composition, so a
+    /// precomposed `ñ` equals `n` followed by a combining tilde.
+    /// Named keys are compared as usual and never equal a character value.
+    ///
+    /// No allocations are made and equal strings are not composed at all.
+    ///
+    /// ```rust
+    /// # use gluten_keyboard::Key;
+    /// assert!(Key::Character("\u{F1}").char_eq(&Key::Character("n\u{303}")));
+    /// assert!(!Key::Character("n").char_eq(&Key::Character("n\u{303}")));
+    /// assert!(Key::Enter.char_eq(&Key::Enter));
+    /// ```
+    pub fn char_eq(&self, other: &Key) -> bool {
+        match (self, other) {
+            (Key::Character(a), Key::Character(b)) => {
+                a == b || Composed::new(a.chars()).eq(Composed::new(b.chars()))
+            }
+            (Key::Character(_), _) | (_, Key::Character(_)) => false,
+            _ => self == other,
+        }
+    }
+}
+
+/// Get the canonical composition of a base character with a combining mark.
+pub(crate) fn compose(base: char, mark: char) -> Option<char> {
+    COMPOSITIONS
+        .binary_search_by_key(&(base, mark), |(b, m, _)| (*b, *m))
+        .ok()
+        .map(|index| COMPOSITIONS[index].2)
+}
+
+/// Remove all combining marks from a precomposed character.
+#[cfg(feature = "alloc")]
+pub(crate) fn base(mut c: char) -> char {
+    while let Some((base, _, _)) = COMPOSITIONS.iter().find(|(_, _, composed)| *composed == c) {
+        c = *base;
+    }
+    c
+}
+
+/// Iterator adapter composing characters with the following combining marks.
+pub(crate) struct Composed<I: Iterator<Item = char>> {
+    chars: I,
+    pending: Option<char>,
+}
+
+impl<I: Iterator<Item = char>> Composed<I> {
+    pub(crate) fn new(mut chars: I) -> Composed<I> {
+        let pending = chars.next();
+        Composed { chars, pending }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for Composed<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let mut current = self.pending.take()?;
+        for c in &mut self.chars {
+            match compose(current, c) {
+                Some(composed) => current = composed,
+                None => {
+                    self.pending = Some(c);
+                    break;
+                }
+            }
+        }
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_composed() {
+        assert!(Composed::new("e\u{323}\u{302}x".chars()).eq("\u{1EC7}x".chars()));
+        assert!(Composed::new("\u{438}\u{306}".chars()).eq("\u{439}".chars()));
+        assert!(Composed::new("\u{301}a".chars()).eq("\u{301}a".chars()));
+        assert!(Composed::new("".chars()).eq("".chars()));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_base() {
+        assert_eq!(base('\u{1EC7}'), 'e');
+        assert_eq!(base('\u{439}'), '\u{438}');
+        assert_eq!(base('x'), 'x');
+    }
+}